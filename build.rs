@@ -0,0 +1,94 @@
+//! Generates a test for each day, input file and part with an expected answer on the
+//! answers file of each year, named like `day9_part2_input9Test`, so that a wrong answer is
+//! reported on its own. Each is solved with the parameters recorded with its answer. The tests
+//! are included by the runner's test module. Years other than 2024 have their files on
+//! `data/{year}/`, and their tests named like `year2023_day9_...`.
+
+use std::path::Path;
+use std::{env, fs};
+
+// Identifier from a file name, which may have characters not valid on one
+fn ident(s: &str) -> String {
+    s.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+// Parameters of an answers line, `name=value` pairs separated by commas, if `s` is one. As
+// on the runner's `verify`, they are the last field of the line, after the answer
+fn parse_params(s: &str) -> Option<Vec<(&str, &str)>> {
+    s.split(',')
+        .map(|param| param.split_once('=').filter(|(name, _)| !name.is_empty()))
+        .collect()
+}
+
+// Year of the files directly on `data/`, as on the runner
+const DEFAULT_YEAR: u16 = 2024;
+
+// Tests of the expected answers of `year`, with its files on `dir`
+fn answer_tests(year: u16, dir: &Path) -> String {
+    let answers_path = dir.join("answers");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    println!("cargo:rerun-if-changed={}", answers_path.display());
+    let prefix = if year == DEFAULT_YEAR {
+        String::new()
+    } else {
+        format!("year{year}_")
+    };
+
+    let answers = fs::read_to_string(&answers_path).unwrap_or_default();
+    let mut tests = String::new();
+    for (i, line) in answers.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields = line.splitn(4, ' ').collect::<Vec<_>>();
+        let [day, file, part @ ("1" | "2"), rest] = fields[..] else {
+            panic!(
+                "Invalid {}, line {}: '{line}'",
+                answers_path.display(),
+                i + 1
+            );
+        };
+        let (answer, params) = rest
+            .rsplit_once(' ')
+            .and_then(|(answer, params)| Some((answer, parse_params(params)?)))
+            .unwrap_or((rest, Vec::new()));
+        // Answers of the input files that aren't on the repo can't be tested
+        let input = dir.join(file);
+        if !input.is_file() {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", input.display());
+        tests += &format!(
+            "#[test]\n\
+             fn {prefix}day{day}_part{part}_{}() {{\n\
+             \x20   check_answer({year}, {day}, {part}, include_str!({:?}), {answer:?}, &{params:?});\n\
+             }}\n\n",
+            ident(file),
+            input.display().to_string(),
+        );
+    }
+    tests
+}
+
+fn main() {
+    let data = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("data");
+    let mut tests = answer_tests(DEFAULT_YEAR, &data);
+    let mut years = fs::read_dir(&data)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let year = entry.file_name().to_str()?.parse::<u16>().ok()?;
+            (year != DEFAULT_YEAR && entry.path().is_dir()).then(|| (year, entry.path()))
+        })
+        .collect::<Vec<_>>();
+    years.sort();
+    for (year, dir) in years {
+        tests += &answer_tests(year, &dir);
+    }
+
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("answer_tests.rs");
+    fs::write(out, tests).unwrap();
+}
@@ -0,0 +1,181 @@
+use std::fmt;
+
+use crate::{Grid, ParseError};
+
+/// The answer to a puzzle part. Integer answers are equal when their values are, whatever
+/// their variant, so `Int(42) == UInt(42)`, the same as when compared with integer literals
+#[derive(Debug, Clone)]
+pub enum Answer {
+    None,
+    Int(i64),
+    UInt(u64),
+    /// Integers beyond 64 bits, limited to 128 bits
+    I128(i128),
+    Str(String),
+    /// Visual answers, that must be read from the grid
+    Grid(Grid<char>),
+}
+
+/// Errors that prevent a puzzle part from being solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AoCError {
+    /// The input couldn't be parsed
+    Parse(ParseError),
+    /// The input was parsed, but isn't valid for the puzzle
+    Input(String),
+    /// The input is valid, but there's no solution for it
+    NoSolution(String),
+}
+
+pub type AoCResult = Result<Answer, AoCError>;
+
+impl Answer {
+    /// Integer value of the answer, if it is an integer
+    pub fn as_i128(&self) -> Option<i128> {
+        match *self {
+            Answer::Int(v) => Some(v as i128),
+            Answer::UInt(v) => Some(v as i128),
+            Answer::I128(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    /// Name of the type of the answer
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::None => "none",
+            Answer::Int(_) => "int",
+            Answer::UInt(_) => "uint",
+            Answer::I128(_) => "i128",
+            Answer::Str(_) => "str",
+            Answer::Grid(_) => "grid",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::None => write!(f, "No value"),
+            Answer::Int(v) => write!(f, "{v}"),
+            Answer::UInt(v) => write!(f, "{v}"),
+            Answer::I128(v) => write!(f, "{v}"),
+            Answer::Str(v) => write!(f, "{v}"),
+            Answer::Grid(v) => write!(f, "{v}"),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::None, Answer::None) => true,
+            (Answer::Str(a), Answer::Str(b)) => a == b,
+            (Answer::Grid(a), Answer::Grid(b)) => a == b,
+            _ => self.as_i128().is_some() && self.as_i128() == other.as_i128(),
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for AoCError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AoCError::Parse(err) => write!(f, "Invalid input: {err}"),
+            AoCError::Input(msg) => write!(f, "Invalid input: {msg}"),
+            AoCError::NoSolution(msg) => write!(f, "No solution: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AoCError {}
+
+impl From<ParseError> for AoCError {
+    fn from(err: ParseError) -> AoCError {
+        AoCError::Parse(err)
+    }
+}
+
+// Conversions and comparisons with integer literals, compared by value regardless of the variant
+macro_rules! impl_int {
+    ( $( $t:ty => $variant:ident as $as:ty ),* ) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(v: $t) -> Answer {
+                    Answer::$variant(v as $as)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    self.as_i128() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i32 => Int as i64,
+          u32 => UInt as u64,
+          i64 => Int as i64,
+          u64 => UInt as u64,
+          usize => UInt as u64,
+          i128 => I128 as i128);
+
+impl From<String> for Answer {
+    fn from(v: String) -> Answer {
+        Answer::Str(v)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(v: &str) -> Answer {
+        Answer::Str(v.to_string())
+    }
+}
+
+impl From<Grid<char>> for Answer {
+    fn from(v: Grid<char>) -> Answer {
+        Answer::Grid(v)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, Answer::Str(v) if v == other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pos;
+
+    #[test]
+    fn test_eq_literals() {
+        assert_eq!(Answer::from(42u64), 42);
+        assert_eq!(Answer::Int(42), 42u64);
+        assert_eq!(Answer::from(42usize), Answer::UInt(42));
+        assert_eq!(Answer::from(i128::MAX), i128::MAX);
+        assert_ne!(Answer::Int(-1), u64::MAX);
+        assert_eq!(Answer::from("6,1"), "6,1");
+        assert_ne!(Answer::Int(61), "61");
+        assert_ne!(Answer::None, 0);
+        // Integer variants are equal by value, like with the literals
+        assert_eq!(Answer::Int(42), Answer::UInt(42));
+        assert_eq!(Answer::I128(-1), Answer::Int(-1));
+        assert_ne!(Answer::UInt(42), Answer::Str("42".to_string()));
+        assert_ne!(Answer::None, Answer::Str(String::new()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(Answer::from(-3).to_string(), "-3");
+        assert_eq!(Answer::from("a,b").to_string(), "a,b");
+        let grid = Grid::new(Pos { x: 2, y: 2 }, '#');
+        assert_eq!(Answer::from(grid).to_string(), "##\n##");
+        assert_eq!(Answer::None.type_name(), "none");
+    }
+}
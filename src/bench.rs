@@ -0,0 +1,295 @@
+//! Benchmark of the days, with statistics of many runs of each step, and comparison against
+//! a baseline saved from a previous benchmark.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use aoc2024::{Entry, ParseError, Parts};
+
+/// Options of a benchmark
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    /// Runs before the measured ones, to warm up the caches
+    pub warmup: u32,
+    /// Measured runs
+    pub samples: u32,
+    /// File to save the results to, as a baseline for later runs
+    pub save: Option<String>,
+    /// Baseline file to compare the results against
+    pub baseline: Option<String>,
+    /// Change of the median, in percent, above which a step is flagged as a regression
+    pub threshold: f64,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            warmup: 3,
+            samples: 20,
+            save: None,
+            baseline: None,
+            threshold: 10.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Statistics of a non-empty set of samples
+    pub fn new(samples: &mut [Duration]) -> Stats {
+        samples.sort_unstable();
+        let n = samples.len();
+        let median = if n % 2 == 1 {
+            samples[n / 2]
+        } else {
+            (samples[n / 2 - 1] + samples[n / 2]) / 2
+        };
+        let mean = samples.iter().sum::<Duration>() / n as u32;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Stats {
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Timed steps of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Step {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::PartOne => "part1",
+            Step::PartTwo => "part2",
+        }
+    }
+
+    fn from_name(s: &str) -> Option<Step> {
+        [Step::Parse, Step::PartOne, Step::PartTwo]
+            .into_iter()
+            .find(|step| step.name() == s)
+    }
+}
+
+/// Statistics of each step of a day. Parts solved together with part one are timed on it,
+/// and have no statistics of their own
+#[derive(Debug)]
+pub struct DayBench {
+    pub day: u16,
+    pub steps: Vec<(Step, Stats)>,
+    pub shared: bool,
+}
+
+/// Runs `entry` `warmup` times, and then measures its steps over `samples` runs
+pub fn bench(
+    entry: &Entry,
+    input: &str,
+    parts: Parts,
+    options: &Options,
+) -> Result<DayBench, ParseError> {
+    for _ in 0..options.warmup {
+        entry.run(input, parts)?;
+    }
+    let mut shared = false;
+    let mut samples: [Vec<Duration>; 3] = Default::default();
+    for _ in 0..options.samples.max(1) {
+        let run = entry.run(input, parts)?;
+        shared = run.shared;
+        samples[0].push(run.parse_time);
+        if let Some(part) = &run.part_one {
+            samples[1].push(part.time);
+        }
+        if let (Some(part), false) = (&run.part_two, run.shared) {
+            samples[2].push(part.time);
+        }
+    }
+
+    let steps = [Step::Parse, Step::PartOne, Step::PartTwo]
+        .into_iter()
+        .zip(samples.iter_mut())
+        .filter(|(_, samples)| !samples.is_empty())
+        .map(|(step, samples)| (step, Stats::new(samples)))
+        .collect();
+    Ok(DayBench {
+        day: entry.day,
+        steps,
+        shared,
+    })
+}
+
+/// Median of each step of each day, in a previous benchmark
+pub type Baseline = HashMap<(u16, Step), Duration>;
+
+/// Baseline file, with a line for each step of each day: `day step min median mean stddev`,
+/// with the times in ns
+pub fn format_baseline(benches: &[DayBench]) -> String {
+    let mut res = String::from("# day step min_ns median_ns mean_ns stddev_ns\n");
+    for bench in benches {
+        for (step, stats) in &bench.steps {
+            res += &format!(
+                "{} {} {} {} {} {}\n",
+                bench.day,
+                step.name(),
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos(),
+                stats.stddev.as_nanos()
+            );
+        }
+    }
+    res
+}
+
+/// Parses a baseline file, as written by `format_baseline`
+pub fn parse_baseline(s: &str) -> Result<Baseline, String> {
+    let mut res = Baseline::new();
+    for (i, line) in s.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || format!("Invalid baseline, line {}: '{line}'", i + 1);
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let [day, step, _, median, ..] = fields[..] else {
+            return Err(invalid());
+        };
+        let day = day.parse().map_err(|_| invalid())?;
+        let step = Step::from_name(step).ok_or_else(invalid)?;
+        let median = median.parse().map_err(|_| invalid())?;
+        res.insert((day, step), Duration::from_nanos(median));
+    }
+    Ok(res)
+}
+
+fn ms(time: Duration) -> String {
+    format!("{:.3}", time.as_secs_f64() * 1000.0)
+}
+
+/// Markdown table with the statistics of each step, in ms. With a `baseline`, the change of
+/// each median is shown, flagging those above `threshold` percent. Returns the table and the
+/// number of regressions
+pub fn table(benches: &[DayBench], baseline: Option<&Baseline>, threshold: f64) -> (String, usize) {
+    let mut res =
+        String::from("|  Day  |  Step  |    Min    |  Median   |   Mean    |  Std dev  |");
+    if baseline.is_some() {
+        res += " Baseline  |     Change      |";
+    }
+    res += "\n|:-----:|:------:|----------:|----------:|----------:|----------:|";
+    if baseline.is_some() {
+        res += "----------:|:---------------:|";
+    }
+
+    let mut regressions = 0;
+    for bench in benches {
+        for (step, stats) in &bench.steps {
+            res += &format!(
+                "\n|{:^7}|{:^8}|{:>10} |{:>10} |{:>10} |{:>10} |",
+                bench.day,
+                step.name(),
+                ms(stats.min),
+                ms(stats.median),
+                ms(stats.mean),
+                ms(stats.stddev)
+            );
+            let Some(baseline) = baseline else { continue };
+            let (old, change) = match baseline.get(&(bench.day, *step)) {
+                Some(&old) if !old.is_zero() => {
+                    let change = (stats.median.as_secs_f64() / old.as_secs_f64() - 1.0) * 100.0;
+                    let flag = if change > threshold {
+                        regressions += 1;
+                        " REGRESSION"
+                    } else {
+                        ""
+                    };
+                    (ms(old), format!("{change:+.1}%{flag}"))
+                }
+                _ => ("-".to_string(), "-".to_string()),
+            };
+            res += &format!("{old:>10} |{change:^17}|");
+        }
+        if bench.shared {
+            res += &format!(
+                "\n|{:^7}|{:^8}|{:>10} |{:>10} |{:>10} |{:>10} |",
+                bench.day,
+                Step::PartTwo.name(),
+                "with part1",
+                "-",
+                "-",
+                "-"
+            );
+            if baseline.is_some() {
+                res += &format!("{:>10} |{:^17}|", "-", "-");
+            }
+        }
+    }
+    (res, regressions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn us(v: u64) -> Duration {
+        Duration::from_micros(v)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&mut [us(4), us(1), us(3), us(8)]);
+        assert_eq!(stats.min, us(1));
+        assert_eq!(stats.median, us(3) + Duration::from_nanos(500));
+        assert_eq!(stats.mean, us(4));
+        assert_eq!(stats.stddev.as_nanos(), 2550);
+
+        let stats = Stats::new(&mut [us(5), us(2), us(9)]);
+        assert_eq!((stats.min, stats.median), (us(2), us(5)));
+    }
+
+    #[test]
+    fn test_baseline() {
+        let stats = Stats::new(&mut [us(10)]);
+        let benches = [DayBench {
+            day: 3,
+            steps: vec![(Step::Parse, stats), (Step::PartOne, stats)],
+            shared: false,
+        }];
+        let saved = format_baseline(&benches);
+        assert_eq!(
+            saved,
+            "# day step min_ns median_ns mean_ns stddev_ns\n\
+             3 parse 10000 10000 10000 0\n\
+             3 part1 10000 10000 10000 0\n"
+        );
+        let baseline = parse_baseline(&saved).unwrap();
+        assert_eq!(baseline[&(3, Step::PartOne)], us(10));
+        assert!(parse_baseline("3 part3 1 2 3 4").is_err());
+        assert!(parse_baseline("3 parse 1").is_err());
+
+        // 10us against 8us is a 25% increase
+        let baseline = parse_baseline("3 parse 8000 8000 8000 0").unwrap();
+        let (table, regressions) = table(&benches, Some(&baseline), 20.0);
+        assert_eq!(regressions, 1);
+        assert!(table.contains("+25.0% REGRESSION"));
+        assert_eq!(table.lines().count(), 4);
+        assert_eq!(super::table(&benches, Some(&baseline), 30.0).1, 0);
+    }
+}
@@ -0,0 +1,664 @@
+//! Command line arguments of the runner.
+
+use std::fs;
+use std::path::Path;
+
+use aoc2024::{generate, Parts};
+
+use crate::bench;
+
+/// How the results are printed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    /// A JSON object per line, for each part
+    Json,
+    /// A CSV row for each part, after a header
+    Csv,
+}
+
+/// Year run when none is given, whose files are directly on `data/`
+pub const DEFAULT_YEAR: u16 = 2024;
+
+/// Directory of the input files and expected answers of `year`: `data/{year}`, or `data` for
+/// the default year
+pub fn data_dir(year: u16) -> String {
+    if year == DEFAULT_YEAR {
+        "data".to_string()
+    } else {
+        format!("data/{year}")
+    }
+}
+
+/// How the frames recorded by the days are exported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visualize {
+    /// Animation on the terminal
+    Ansi,
+    /// Frames printed as text
+    Text,
+    /// Images saved on a directory for each input
+    Ppm,
+    Png,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Config {
+    pub year: u16,
+    pub days: Vec<u16>,
+    pub parts: Parts,
+    /// Input files, `-` for stdin, and directories with input files named after their day
+    pub inputs: Vec<String>,
+    pub use_test_file: bool,
+    /// Times each day is run, the reported times are the mean of all runs
+    pub repeat: u32,
+    pub format: Format,
+    /// Only print the answers
+    pub quiet: bool,
+    pub visualize: Option<Visualize>,
+    /// Parameters of the puzzles overridden, by name, as given
+    pub params: Vec<(String, String)>,
+}
+
+/// Options of gen
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct GenOptions {
+    pub seed: u64,
+    /// Size of the input, that of the real inputs if not given
+    pub size: Option<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(Config),
+    /// Benchmark of the days, instead of printing their answers
+    Bench(Config, bench::Options),
+    /// Verification of the answers of every input file of the days
+    Verify(Config),
+    /// Scaffolding of a new day, with its year and day
+    New(u16, u16),
+    /// Random input of a day, printed
+    Gen(u16, GenOptions),
+    Help,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Run,
+    Bench,
+    Verify,
+    New,
+    Gen,
+}
+
+/// Usage text, listing the `available` days of each year
+pub fn usage(available: &[(u16, Vec<u16>)]) -> String {
+    let implemented = available
+        .iter()
+        .map(|(year, days)| format!("    implemented days of {year}: {}", day_ranges(days)))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let generated = generate::GENERATORS
+        .iter()
+        .map(|generator| generator.day)
+        .collect::<Vec<_>>();
+    let generated = day_ranges(&generated);
+    format!(
+        "usage: aoc2024 [options] <days> [files]\n\
+        \x20      aoc2024 bench [options] <days>\n\
+        \x20      aoc2024 verify [options] [days]\n\
+        \x20      aoc2024 new [-y <year>] <day>\n\
+        \x20      aoc2024 gen [--seed <n>] [--size <n>] <day>\n\
+        \n\
+        commands:\n\
+        \x20   bench     benchmark the days, with statistics of many runs\n\
+        \x20   verify    check the answers of all the input files of the days, all by default,\n\
+        \x20             against the expected ones on the year's 'answers' file\n\
+        \x20   new       create the module of a day from the template, register it, and create\n\
+        \x20             its input files and expected answers, without overwriting anything\n\
+        \x20   gen       print a random input of a day of {DEFAULT_YEAR}, for stress and property\n\
+        \x20             tests. Days with a generator: {generated}\n\
+        \n\
+        days:\n\
+        \x20   a day, 'all' for every day, or a range of days like '1..=10' or '1..11'\n\
+        {implemented}\n\
+        \n\
+        options:\n\
+        \x20   -y, --year <year>         year of the days, {DEFAULT_YEAR} by default. Files of other\n\
+        \x20                             years are on 'data/[year]/' instead of 'data/'\n\
+        \x20   -p, --part <1|2>          run only the given part\n\
+        \x20   -i, --input <file>        input file, '-' for stdin, or directory with files named\n\
+        \x20                             'input[day]', optionally followed by a suffix. Can be\n\
+        \x20                             repeated, and files are only for a single day.\n\
+        \x20                             'data/input[day]' is used if none is given\n\
+        \x20   -t, --test                use 'data/input[day]Test' as input\n\
+        \x20   -r, --repeat <n>          run each day n times, reporting the mean times\n\
+        \x20   -f, --format <fmt>        output format: text, the default, json or csv\n\
+        \x20   -q, --quiet               only print the answers\n\
+        \x20   --visualize <fmt>         record the frames of the days that emit them, and\n\
+        \x20                             play them on the terminal, ansi, print them, text,\n\
+        \x20                             or save them as ppm or png images on\n\
+        \x20                             'visualize/[input]/'\n\
+        \x20   --param <name=value>      override a parameter of the puzzles, like 'dims=7x7' for\n\
+        \x20                             the examples of day 18. Can be repeated, and each\n\
+        \x20                             one must be of one of the days\n\
+        \x20   -h, --help                print this help\n\
+        \n\
+        bench options:\n\
+        \x20   --warmup <n>              runs before measuring, 3 by default\n\
+        \x20   --samples <n>             measured runs, 20 by default\n\
+        \x20   --save <file>             save the results as a baseline\n\
+        \x20   --baseline <file>         compare the results against a saved baseline\n\
+        \x20   --threshold <percent>     change of the median flagged as a regression,\n\
+        \x20                             10 by default\n\
+        \n\
+        gen options:\n\
+        \x20   --seed <n>                seed of the input, 0 by default. The same seed and\n\
+        \x20                             size always give the same input\n\
+        \x20   --size <n>                size of the input, whose meaning depends on the day,\n\
+        \x20                             like the side of a map. That of the real inputs by\n\
+        \x20                             default"
+    )
+}
+
+// Compact listing of the days, as ranges of consecutive days
+fn day_ranges(days: &[u16]) -> String {
+    let mut ranges: Vec<(u16, u16)> = Vec::new();
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, last)) if *last + 1 == day => *last = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(first, last)| match last - first {
+            0 => format!("{first}"),
+            1 => format!("{first}, {last}"),
+            _ => format!("{first}..={last}"),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// A single day, all `available` days, or the available days on a range
+pub fn parse_days(s: &str, available: &[u16]) -> Result<Vec<u16>, String> {
+    let invalid = || format!("Invalid days: '{s}'");
+    let range = if s == "all" {
+        1..=u16::MAX
+    } else if let Some((first, last)) = s.split_once("..=") {
+        let first = first.parse().map_err(|_| invalid())?;
+        first..=last.parse().map_err(|_| invalid())?
+    } else if let Some((first, end)) = s.split_once("..") {
+        let first = first.parse().map_err(|_| invalid())?;
+        let end = end.parse::<u16>().map_err(|_| invalid())?;
+        first..=end.checked_sub(1).ok_or_else(invalid)?
+    } else {
+        let day = s.parse().map_err(|_| invalid())?;
+        if !available.contains(&day) {
+            return Err(format!("Day {day} not implemented"));
+        }
+        return Ok(vec![day]);
+    };
+
+    let days = available
+        .iter()
+        .copied()
+        .filter(|day| range.contains(day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err(format!("No implemented days in '{s}'"));
+    }
+    Ok(days)
+}
+
+// Positive count, for the option `name`
+fn count(name: &str, v: &str) -> Result<u32, String> {
+    v.parse()
+        .ok()
+        .filter(|&n| n > 0)
+        .ok_or_else(|| format!("Invalid count for {name}: '{v}'"))
+}
+
+impl Config {
+    /// Parses the arguments, without the program name
+    pub fn build(args: &[String], available: &[(u16, Vec<u16>)]) -> Result<Command, String> {
+        let mut year = DEFAULT_YEAR;
+        let mut days = None;
+        let mut parts = Parts::Both;
+        let mut inputs = Vec::new();
+        let mut use_test_file = false;
+        let mut repeat = 1;
+        let mut format = Format::Text;
+        let mut quiet = false;
+        let mut visualize = None;
+        let mut params = Vec::new();
+        let mut mode = Mode::Run;
+        let mut options = bench::Options::default();
+        let mut gen_options = GenOptions::default();
+        // First option of bench and of gen given, checked once the command is known
+        let mut bench_option = None;
+        let mut gen_option = None;
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            // Options can have their value on the next argument or after an '='
+            let (name, inline_value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name, Some(value.to_string())),
+                _ => (arg.as_str(), None),
+            };
+            let mut value = || {
+                inline_value
+                    .clone()
+                    .or_else(|| args.next().cloned())
+                    .ok_or_else(|| format!("Missing value for {name}"))
+            };
+
+            match name {
+                "--warmup" | "--samples" | "--save" | "--baseline" | "--threshold" => {
+                    bench_option.get_or_insert(name);
+                }
+                "--seed" | "--size" => {
+                    gen_option.get_or_insert(name);
+                }
+                _ => (),
+            }
+            match name {
+                "-h" | "--help" => return Ok(Command::Help),
+                "-t" | "--test" => use_test_file = true,
+                "-y" | "--year" => {
+                    let v = value()?;
+                    // The first year of Advent of Code
+                    year = v
+                        .parse()
+                        .ok()
+                        .filter(|&year| year >= 2015)
+                        .ok_or_else(|| format!("Invalid year: '{v}'"))?;
+                }
+                "-q" | "--quiet" => quiet = true,
+                "-p" | "--part" => {
+                    parts = match value()?.as_str() {
+                        "1" => Parts::One,
+                        "2" => Parts::Two,
+                        v => return Err(format!("Invalid part: '{v}'")),
+                    }
+                }
+                "-i" | "--input" => inputs.push(value()?),
+                "-r" | "--repeat" => repeat = count(name, &value()?)?,
+                "-f" | "--format" => {
+                    format = match value()?.as_str() {
+                        "text" => Format::Text,
+                        "json" => Format::Json,
+                        "csv" => Format::Csv,
+                        v => return Err(format!("Invalid format: '{v}'")),
+                    }
+                }
+                "--visualize" => {
+                    visualize = match value()?.as_str() {
+                        "ansi" => Some(Visualize::Ansi),
+                        "text" => Some(Visualize::Text),
+                        "ppm" => Some(Visualize::Ppm),
+                        "png" => Some(Visualize::Png),
+                        v => return Err(format!("Invalid visualization: '{v}'")),
+                    }
+                }
+                "--param" => {
+                    let v = value()?;
+                    match v.split_once('=') {
+                        Some((name, value)) if !name.is_empty() => {
+                            params.push((name.to_string(), value.to_string()));
+                        }
+                        _ => return Err(format!("Invalid parameter: '{v}', expected name=value")),
+                    }
+                }
+                "--warmup" => {
+                    let v = value()?;
+                    options.warmup = v
+                        .parse()
+                        .map_err(|_| format!("Invalid count for {name}: '{v}'"))?;
+                }
+                "--samples" => options.samples = count(name, &value()?)?,
+                "--save" => options.save = Some(value()?),
+                "--baseline" => options.baseline = Some(value()?),
+                "--threshold" => {
+                    let v = value()?;
+                    options.threshold =
+                        v.parse().map_err(|_| format!("Invalid threshold: '{v}'"))?;
+                }
+                "--seed" => {
+                    let v = value()?;
+                    gen_options.seed = v.parse().map_err(|_| format!("Invalid seed: '{v}'"))?;
+                }
+                "--size" => gen_options.size = Some(count(name, &value()?)? as usize),
+                _ if name.starts_with('-') && name != "-" => {
+                    return Err(format!("Unknown option: '{name}'"));
+                }
+                // Positional arguments, the command, the days and then the input files
+                "bench" if days.is_none() && mode == Mode::Run => mode = Mode::Bench,
+                "verify" if days.is_none() && mode == Mode::Run => mode = Mode::Verify,
+                "new" if days.is_none() && mode == Mode::Run => mode = Mode::New,
+                "gen" if days.is_none() && mode == Mode::Run => mode = Mode::Gen,
+                _ if days.is_none() => days = Some(arg.clone()),
+                _ => inputs.push(arg.clone()),
+            }
+        }
+        match (bench_option, gen_option) {
+            (Some(name), _) if mode != Mode::Bench => {
+                return Err(format!("{name} is only valid for bench"));
+            }
+            (_, Some(name)) if mode != Mode::Gen => {
+                return Err(format!("{name} is only valid for gen"));
+            }
+            _ => (),
+        }
+
+        if mode == Mode::New {
+            // Neither the day nor the year need to be implemented
+            let (Some(day), true) = (days, inputs.is_empty()) else {
+                return Err("new takes a single day".to_string());
+            };
+            return match day.parse() {
+                Ok(day @ 1..=25) => Ok(Command::New(year, day)),
+                _ => Err(format!("Invalid day: '{day}'")),
+            };
+        }
+        if mode == Mode::Gen {
+            let (Some(day), true) = (days, inputs.is_empty()) else {
+                return Err("gen takes a single day".to_string());
+            };
+            if year != DEFAULT_YEAR {
+                return Err(format!("gen only has generators for {DEFAULT_YEAR}"));
+            }
+            return match day.parse() {
+                Ok(day) if generate::generator(day).is_some() => Ok(Command::Gen(day, gen_options)),
+                _ => Err(format!("No generator for day '{day}'")),
+            };
+        }
+        let Some((_, available)) = available.iter().find(|(y, _)| *y == year) else {
+            return Err(format!("Year {year} not implemented"));
+        };
+        let days = match days {
+            Some(days) => parse_days(&days, available)?,
+            None if mode == Mode::Verify => available.clone(),
+            None => return Err("Missing the days to run".to_string()),
+        };
+        if mode == Mode::Verify && (!inputs.is_empty() || use_test_file) {
+            return Err("verify checks all the input files of the days".to_string());
+        }
+        match visualize {
+            Some(_) if mode != Mode::Run => {
+                return Err("--visualize can't be used with bench or verify".to_string());
+            }
+            Some(_) if repeat > 1 => {
+                return Err("--visualize can't be used with --repeat".to_string());
+            }
+            Some(Visualize::Ansi | Visualize::Text) if format != Format::Text => {
+                return Err("ansi and text visualizations need the text format".to_string());
+            }
+            _ => (),
+        }
+        if !params.is_empty() && mode != Mode::Run {
+            return Err("--param can't be used with bench or verify".to_string());
+        }
+        if inputs.iter().filter(|&input| input == "-").count() > 1 {
+            return Err("stdin can only be read once".to_string());
+        }
+        let config = Config {
+            year,
+            days,
+            parts,
+            inputs,
+            use_test_file,
+            repeat,
+            format,
+            quiet,
+            visualize,
+            params,
+        };
+        Ok(match mode {
+            Mode::Run => Command::Run(config),
+            Mode::Bench => Command::Bench(config, options),
+            Mode::Verify => Command::Verify(config),
+            Mode::New | Mode::Gen => unreachable!("new and gen are handled before"),
+        })
+    }
+
+    /// Input files of `day`: the given ones, with directories replaced by their files for
+    /// the day, or the default one
+    pub fn input_paths(&self, day: u16) -> Result<Vec<String>, String> {
+        if self.inputs.is_empty() {
+            let suffix = if self.use_test_file { "Test" } else { "" };
+            return Ok(vec![format!("{}/input{day}{suffix}", data_dir(self.year))]);
+        }
+
+        let mut res = Vec::new();
+        for input in &self.inputs {
+            if !Path::new(input).is_dir() {
+                if self.days.len() > 1 {
+                    return Err(format!("Input file {input} can only be for a single day"));
+                }
+                res.push(input.clone());
+                continue;
+            }
+            let dir = fs::read_dir(input).map_err(|_| format!("Couldn't read dir: {input}"))?;
+            let mut files = dir
+                .filter_map(|file| file.ok()?.file_name().into_string().ok())
+                .filter(|file| is_day_input(file, day))
+                .map(|file| Path::new(input).join(file).display().to_string())
+                .collect::<Vec<_>>();
+            files.sort();
+            res.extend(files);
+        }
+        Ok(res)
+    }
+}
+
+/// Whether the file `name` is an input of `day`: `input{day}`, possibly followed by a suffix
+/// like `Test`
+pub fn is_day_input(name: &str, day: u16) -> bool {
+    name.strip_prefix(&format!("input{day}"))
+        .is_some_and(|suffix| !suffix.starts_with(|c: char| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AVAILABLE: [u16; 4] = [1, 2, 3, 5];
+
+    fn build(args: &str) -> Result<Command, String> {
+        let args = args
+            .split_whitespace()
+            .map(String::from)
+            .collect::<Vec<_>>();
+        Config::build(&args, &[(2023, vec![1, 2]), (2024, AVAILABLE.to_vec())])
+    }
+
+    fn config(args: &str) -> Config {
+        match build(args) {
+            Ok(Command::Run(config)) => config,
+            res => panic!("Unexpected {res:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_days() {
+        assert_eq!(parse_days("2", &AVAILABLE), Ok(vec![2]));
+        assert_eq!(parse_days("all", &AVAILABLE), Ok(vec![1, 2, 3, 5]));
+        assert_eq!(parse_days("2..=5", &AVAILABLE), Ok(vec![2, 3, 5]));
+        assert_eq!(parse_days("2..5", &AVAILABLE), Ok(vec![2, 3]));
+        assert!(parse_days("4", &AVAILABLE).is_err());
+        assert!(parse_days("6..=9", &AVAILABLE).is_err());
+        assert!(parse_days("x", &AVAILABLE).is_err());
+        assert_eq!(day_ranges(&AVAILABLE), "1..=3, 5");
+    }
+
+    #[test]
+    fn test_build() {
+        let c = config("3");
+        assert_eq!(
+            (&c.days[..], c.parts, c.format),
+            (&[3][..], Parts::Both, Format::Text)
+        );
+        assert_eq!(c.input_paths(3).unwrap(), ["data/input3"]);
+        assert_eq!(config("-t 3").input_paths(3).unwrap(), ["data/input3Test"]);
+
+        // Positional files, as before the options
+        assert_eq!(config("3 file").inputs, ["file"]);
+        assert_eq!(config("3 a -i b c").inputs, ["a", "b", "c"]);
+
+        let c = config("--part 2 -i - --repeat=10 -f json -q 5");
+        assert_eq!(c.days, [5]);
+        assert_eq!(c.parts, Parts::Two);
+        assert_eq!(c.inputs, ["-"]);
+        assert_eq!((c.repeat, c.format, c.quiet), (10, Format::Json, true));
+
+        assert_eq!(build("1 -h"), Ok(Command::Help));
+        assert_eq!(config("all").days, AVAILABLE);
+        assert!(build("").is_err());
+        assert!(build("4").is_err());
+        assert!(build("1 --part 3").is_err());
+        assert!(build("1 --repeat 0").is_err());
+        assert_eq!(config("1 --format=csv").format, Format::Csv);
+        assert!(build("1 --format").is_err());
+        assert!(build("1 --verbose").is_err());
+        assert!(build("1 - -").is_err());
+    }
+
+    #[test]
+    fn test_build_visualize() {
+        assert_eq!(config("1").visualize, None);
+        assert_eq!(config("2 --visualize png").visualize, Some(Visualize::Png));
+        let c = config("--visualize=ansi 3 -t");
+        assert_eq!(c.visualize, Some(Visualize::Ansi));
+        assert_eq!(
+            config("1 --visualize ppm -f csv").visualize,
+            Some(Visualize::Ppm)
+        );
+        assert!(build("1 --visualize gif").is_err());
+        assert!(build("1 --visualize text -f json").is_err());
+        assert!(build("1 --visualize text -r 2").is_err());
+        assert!(build("bench 1 --visualize text").is_err());
+    }
+
+    #[test]
+    fn test_build_params() {
+        assert!(config("1").params.is_empty());
+        let c = config("2 --param dims=7x7 --param=cutoff_time=12 -t");
+        let params = [("dims", "7x7"), ("cutoff_time", "12")]
+            .map(|(name, value)| (name.to_string(), value.to_string()));
+        assert_eq!(c.params, params);
+        assert_eq!(config("1 --param steps=").params[0].1, "");
+        assert!(build("1 --param dims").is_err());
+        assert!(build("1 --param =7x7").is_err());
+        assert!(build("1 --param").is_err());
+        assert!(build("bench 1 --param dims=7x7").is_err());
+        assert!(build("verify --param dims=7x7").is_err());
+    }
+
+    #[test]
+    fn test_build_year() {
+        assert_eq!(config("3").year, DEFAULT_YEAR);
+        let c = config("-y 2023 2");
+        assert_eq!((c.year, &c.days[..]), (2023, &[2][..]));
+        assert_eq!(c.input_paths(2).unwrap(), ["data/2023/input2"]);
+        assert_eq!(config("all --year=2023").days, [1, 2]);
+        assert!(build("--year 2023 3").is_err());
+        assert!(build("-y 2022 1").is_err());
+        assert!(build("-y 24 1").is_err());
+        assert!(matches!(build("verify -y 2023"), Ok(Command::Verify(c)) if c.days == [1, 2]));
+    }
+
+    #[test]
+    fn test_input_paths() {
+        assert!(is_day_input("input1", 1) && is_day_input("input1Test", 1));
+        assert!(!is_day_input("input10", 1) && !is_day_input("answers", 1));
+
+        // Directories have the inputs of each day
+        let c = config("1..=3 -i data");
+        assert_eq!(
+            c.input_paths(2).unwrap(),
+            ["data/input2", "data/input2Test"]
+        );
+        assert_eq!(config("1 -i data -i a").input_paths(1).unwrap().len(), 3);
+        assert!(config("all --input file").input_paths(1).is_err());
+    }
+
+    #[test]
+    fn test_build_bench() {
+        let options = bench::Options {
+            samples: 50,
+            baseline: Some("base".to_string()),
+            threshold: 5.0,
+            ..Default::default()
+        };
+        match build("bench all --samples 50 --baseline=base --threshold 5 -p 1") {
+            Ok(Command::Bench(config, opts)) => {
+                assert_eq!(
+                    (config.days, config.parts),
+                    (AVAILABLE.to_vec(), Parts::One)
+                );
+                assert_eq!(opts, options);
+            }
+            res => panic!("Unexpected {res:?}"),
+        }
+        assert!(build("all --samples 50").is_err());
+        // Options of bench can come before it, like the others
+        assert!(matches!(
+            build("--samples 50 -p 1 bench all --baseline=base --threshold 5"),
+            Ok(Command::Bench(_, opts)) if opts == options
+        ));
+        assert!(build("bench all --warmup x").is_err());
+        assert!(build("bench").is_err());
+    }
+
+    #[test]
+    fn test_build_verify() {
+        match build("verify -p 2") {
+            Ok(Command::Verify(config)) => {
+                assert_eq!(
+                    (config.days, config.parts),
+                    (AVAILABLE.to_vec(), Parts::Two)
+                );
+            }
+            res => panic!("Unexpected {res:?}"),
+        }
+        assert!(matches!(build("verify 2..=3"), Ok(Command::Verify(c)) if c.days == [2, 3]));
+        assert!(build("verify 1 -t").is_err());
+        assert!(build("verify 1 file").is_err());
+        assert!(build("verify --samples 5").is_err());
+    }
+
+    #[test]
+    fn test_build_new() {
+        // The day doesn't need to be implemented
+        assert_eq!(build("new 4"), Ok(Command::New(DEFAULT_YEAR, 4)));
+        assert_eq!(build("new -y 2022 4"), Ok(Command::New(2022, 4)));
+        assert!(build("new 26").is_err());
+        assert!(build("new").is_err());
+        assert!(build("new 4 5").is_err());
+    }
+
+    #[test]
+    fn test_build_gen() {
+        assert_eq!(build("gen 6"), Ok(Command::Gen(6, GenOptions::default())));
+        let options = GenOptions {
+            seed: 42,
+            size: Some(20),
+        };
+        assert_eq!(
+            build("gen --seed 42 24 --size=20"),
+            Ok(Command::Gen(24, options))
+        );
+        // Generated days don't need to be available
+        assert!(matches!(build("gen 16"), Ok(Command::Gen(16, _))));
+        assert!(build("gen 3").is_err());
+        assert!(build("gen 6 7").is_err());
+        assert!(build("gen -y 2023 6").is_err());
+        assert!(build("gen 6 --size 0").is_err());
+        assert!(matches!(build("--seed 42 gen 6"), Ok(Command::Gen(6, opts)) if opts.seed == 42));
+        assert!(build("--seed 42 6").is_err());
+        assert!(build("gen 6 --seed -1").is_err());
+        assert!(build("1 --seed 4").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// Left and right lists of location ids
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let vals: Vec<(i32, i32)> = input
+        .trim()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let mut it = line.split_whitespace();
+            Ok((
+                parse::num(parse::next(&mut it, line, "a number")?)?,
+                parse::num(parse::next(&mut it, line, "a number")?)?,
+            ))
+        })
+        .try_collect()?;
+
+    let lst1 = vals.iter().map(|row| row.0).collect_vec();
+    let lst2 = vals.iter().map(|row| row.1).collect_vec();
+    Ok((lst1, lst2))
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Input<'a> = (Vec<i32>, Vec<i32>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((lst1, lst2): &Self::Input<'_>) -> AoCResult {
+        let (mut lst1, mut lst2) = (lst1.clone(), lst2.clone());
+        lst1.sort();
+        lst2.sort();
+
+        let diff: i32 = (0..lst1.len()).map(|i| (lst1[i] - lst2[i]).abs()).sum();
+        Ok(Answer::from(diff))
+    }
+
+    fn part_two((lst1, lst2): &Self::Input<'_>) -> AoCResult {
+        // Build histogram of values in second list
+        let mut histogram: HashMap<i32, i32> = HashMap::new();
+        lst2.iter()
+            .for_each(|v| *histogram.entry(*v).or_insert(0) += 1);
+        // Use histogram to calculate result
+        let res: i32 = lst1
+            .iter()
+            .map(|v| v * histogram.get(v).unwrap_or(&0))
+            .sum();
+        Ok(Answer::from(res))
+    }
+}
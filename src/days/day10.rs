@@ -0,0 +1,92 @@
+use crate::search;
+use crate::{Answer, AoCResult, Grid, ParseError, Pos, Solver};
+
+/// Height map, and the trailheads on it
+pub fn parse_input(input: &str) -> Result<(Grid<u32>, Vec<Pos>), ParseError> {
+    let height_map = Grid::parse_with(input, "a digit", |c| c.to_digit(10))?;
+    let trailheads = height_map.find_all(&0).collect::<Vec<_>>();
+    Ok((height_map, trailheads))
+}
+
+/// Returns the number of summits reachable from `pos` and the number of distinct trails to them
+pub fn score(pos: Pos, height_map: &Grid<u32>) -> (u32, u32) {
+    // Every step goes up by one, so all trails to a summit are shortest paths
+    let explored = search::bfs_all(
+        pos,
+        |&p| {
+            height_map
+                .neighbors(p)
+                .filter(move |&n| height_map[n] == height_map[p] + 1)
+        },
+        |_| false,
+    );
+    let summits = explored
+        .cost
+        .keys()
+        .filter(|&&p| height_map[p] == 9)
+        .collect::<Vec<_>>();
+    let trails = summits
+        .iter()
+        .map(|&&p| explored.count_paths(p))
+        .sum::<u64>();
+
+    (summits.len() as u32, trails as u32)
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Input<'a> = (Grid<u32>, Vec<Pos>);
+    type Params = ();
+    // Both parts come from the same search from each trailhead
+    const SHARED: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((height_map, trailheads): &Self::Input<'_>) -> AoCResult {
+        let res = trailheads
+            .iter()
+            .map(|t| score(*t, height_map).0)
+            .sum::<u32>();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((height_map, trailheads): &Self::Input<'_>) -> AoCResult {
+        let res = trailheads
+            .iter()
+            .map(|t| score(*t, height_map).1)
+            .sum::<u32>();
+        Ok(Answer::from(res))
+    }
+
+    fn solve_both((height_map, trailheads): &Self::Input<'_>) -> (AoCResult, AoCResult) {
+        let (summits, trails) = trailheads
+            .iter()
+            .map(|t| score(*t, height_map))
+            .fold((0, 0), |acc, score| (acc.0 + score.0, acc.1 + score.1));
+        (Ok(Answer::from(summits)), Ok(Answer::from(trails)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        include_str!("../../data/input10Test"),
+        include_str!("../../data/input10"),
+    ];
+    const EXPECTED_PART_ONE: [i64; 2] = [36, 582];
+    const EXPECTED_PART_TWO: [i64; 2] = [81, 1302];
+
+    #[test]
+    fn test_solve_both() {
+        for i in 0..2 {
+            let (one, two) = Day10::solve_both(&Day10::parse(INPUT[i]).unwrap());
+            assert_eq!(one.unwrap(), EXPECTED_PART_ONE[i]);
+            assert_eq!(two.unwrap(), EXPECTED_PART_TWO[i]);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use crate::{params, parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+
+/// Number of stones engraved with each number
+pub type CountsMap = HashMap<u64, usize>;
+
+/// Stones, counted by their number
+pub fn parse_input(input: &str) -> Result<CountsMap, ParseError> {
+    let stones: Vec<u64> = input.split_whitespace().map(parse::num).try_collect()?;
+    Ok(stones.into_iter().counts())
+}
+
+/// Stones after blinking once
+pub fn step_once(counts: CountsMap) -> CountsMap {
+    let mut res = HashMap::new();
+    for (&k, &v) in counts.iter() {
+        let new_k1;
+        let mut new_k2 = None;
+
+        if k == 0 {
+            new_k1 = 1;
+        } else {
+            let len = k.ilog10() + 1;
+            if (len % 2) == 0 {
+                new_k1 = k / 10u64.pow(len / 2);
+                new_k2 = Some(k % 10u64.pow(len / 2));
+            } else {
+                new_k1 = k * 2024;
+            }
+        }
+        *res.entry(new_k1).or_insert(0) += v;
+        if let Some(k2) = new_k2 {
+            *res.entry(k2).or_insert(0) += v;
+        }
+    }
+    res
+}
+
+/// Stones after blinking `n` times
+pub fn step_n(mut counts: CountsMap, n: usize) -> CountsMap {
+    for _ in 0..n {
+        counts = step_once(counts);
+    }
+    counts
+}
+
+params! {
+    /// Times the stones are blinked at on each part
+    pub struct Day11Params {
+        blinks_one: usize = 25,
+        blinks_two: usize = 75,
+    }
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Input<'a> = (CountsMap, Day11Params);
+    type Params = Day11Params;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Day11Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Day11Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse_input(input)?, *params))
+    }
+
+    fn part_one((stones, params): &Self::Input<'_>) -> AoCResult {
+        let steps = params.blinks_one;
+        let counts = step_n(stones.clone(), steps);
+        let res: usize = counts.values().sum();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((stones, params): &Self::Input<'_>) -> AoCResult {
+        let steps = params.blinks_two;
+        let counts = step_n(stones.clone(), steps);
+        let res: usize = counts.values().sum();
+        Ok(Answer::from(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../data/input11Test");
+
+    #[test]
+    fn test_part_one() {
+        let params = Day11Params {
+            blinks_one: 6,
+            ..Default::default()
+        };
+        assert_eq!(Day11::solve_part_one_with(INPUT, &params).unwrap(), 22);
+    }
+}
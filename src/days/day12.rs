@@ -0,0 +1,98 @@
+use crate::{Answer, AoCResult, Dir, Grid, ParseError, Solver};
+
+/// Garden, as a grid of plant types
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Total price of fencing the regions, by their perimeter or, with `part_two`, by their
+/// number of sides
+pub fn solve(grid: &Grid<char>, part_two: bool) -> AoCResult {
+    // Whether each position has been visited
+    let mut visited = Grid::new(grid.dims, false);
+    // For each position store a bitmask with bits set for the directions that are outside
+    let mut outside = Grid::new(grid.dims, 0u8);
+    let mut res = 0;
+
+    // Iterate on grid
+    for start in grid.positions() {
+        if visited[start] {
+            continue;
+        }
+        // Adjacent positions to be explored
+        let mut frontier = vec![start];
+        let mut area = 0;
+        let mut perimeter = 0;
+        // DFS exploration
+        while let Some(pos) = frontier.pop() {
+            if visited[pos] {
+                continue;
+            }
+            visited[pos] = true;
+            area += 1;
+            let mut neighbors = Vec::new();
+            for (i, &d) in Dir::CARDINAL.iter().enumerate() {
+                if pos.can_move_by(d, grid.dims) {
+                    let new_pos = pos + d;
+                    if grid[new_pos] == grid[start] {
+                        // Same type as current, continue exploration and mark neighbor
+                        frontier.push(new_pos);
+                        neighbors.push(new_pos);
+                        continue;
+                    }
+                }
+                // Position on this direction is outside the grid or different, mark perimeter
+                outside[pos] |= 1 << i;
+            }
+
+            perimeter += outside[pos].count_ones();
+            if part_two {
+                // Calculate common sides with neighbors and subtract
+                let mut common_sides = 0;
+                for neighbor in neighbors {
+                    common_sides += (outside[pos] & outside[neighbor]).count_ones();
+                }
+                perimeter -= common_sides;
+            }
+        }
+        res += area * perimeter;
+    }
+
+    Ok(Answer::from(res))
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> AoCResult {
+        solve(grid, false)
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> AoCResult {
+        solve(grid, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input1 = "AAAA\nBBCD\nBBCC\nEEEC\n";
+        assert_eq!(Day12::solve_part_one(input1).unwrap(), 140);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input1 = "AAAA\nBBCD\nBBCC\nEEEC\n";
+        assert_eq!(Day12::solve_part_two(input1).unwrap(), 80);
+    }
+}
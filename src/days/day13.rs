@@ -0,0 +1,178 @@
+use crate::{math, parse, Answer, AoCResult, ParseError, Solver};
+
+/// Claw machine, with the position of its prize and the moves of its buttons A and B
+#[derive(Debug, Copy, Clone)]
+pub struct Machine {
+    pub prize: [i64; 2],
+    pub buttons: [[i64; 2]; 2],
+}
+
+// Parses the "X+a, Y+b" coordinates after `prefix`, `sep` being the char after X and Y
+fn parse_coords(line: &str, prefix: &str, sep: char) -> Result<[i64; 2], ParseError> {
+    let (x, y) = parse::split_once(parse::strip_prefix(line, prefix)?, ", ")?;
+    Ok([
+        parse::num(parse::strip_prefix(x, &format!("X{sep}"))?)?,
+        parse::num(parse::strip_prefix(y, &format!("Y{sep}"))?)?,
+    ])
+}
+
+/// Claw machines
+pub fn parse_input(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let mut machines = Vec::new();
+    let mut next = Machine {
+        prize: [0, 0],
+        buttons: [[0, 0], [0, 0]],
+    };
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        if line.starts_with("Button A:") {
+            next.buttons[0] = parse_coords(line, "Button A: ", '+')?;
+        } else if line.starts_with("Button B:") {
+            next.buttons[1] = parse_coords(line, "Button B: ", '+')?;
+        } else if line.starts_with("Prize:") {
+            next.prize = parse_coords(line, "Prize: ", '=')?;
+            machines.push(next);
+        } else {
+            return Err(ParseError::new(line, "a button or prize"));
+        }
+    }
+    Ok(machines)
+}
+
+// 2x2 matrix determinant
+fn determinant(matrix: &[[i64; 2]; 2]) -> i64 {
+    matrix[0][0] * matrix[1][1] - matrix[0][1] * matrix[1][0]
+}
+
+// Cheapest `3 * a + b` with `a * step_a + b * step_b = target`, for non negative a and b and
+// steps. The solutions are `a = a0 + k * step_b / g` and `b = b0 - k * step_a / g`, whose cost
+// is linear on k, so the cheapest is on one end of the range of k where both are non negative
+fn cheapest_on_line(step_a: i64, step_b: i64, target: i64) -> Option<i64> {
+    let (g, x, y) = math::ext_gcd(step_a, step_b);
+    if g == 0 {
+        return (target == 0).then_some(0);
+    }
+    if target % g != 0 {
+        return None;
+    }
+    let (a0, b0) = (x * (target / g), y * (target / g));
+    let (da, db) = (step_b / g, step_a / g);
+    // Lowest k with a >= 0, and highest with b >= 0, if bounded
+    let k_min = if da > 0 {
+        Some(-a0.div_euclid(da))
+    } else if a0 >= 0 {
+        None
+    } else {
+        return None;
+    };
+    let k_max = if db > 0 {
+        Some(b0.div_euclid(db))
+    } else if b0 >= 0 {
+        None
+    } else {
+        return None;
+    };
+    if let (Some(k_min), Some(k_max)) = (k_min, k_max) {
+        if k_min > k_max {
+            return None;
+        }
+    }
+    let k = if 3 * da - db > 0 {
+        k_min
+    } else {
+        k_max.or(k_min)
+    }?;
+    Some(3 * (a0 + k * da) + b0 - k * db)
+}
+
+/// Tokens to win the prize at `b`, with buttons moving by the rows of `matrix`, or 0 if it
+/// can't be won
+pub fn solve_linear_eq(b: &[i64; 2], matrix: &[[i64; 2]; 2]) -> i64 {
+    let det = determinant(matrix);
+    let [[ax, ay], [bx, by]] = *matrix;
+    if det == 0 {
+        // Buttons on the same line, which the prize must be on too
+        let dir = if matrix[0] != [0, 0] {
+            matrix[0]
+        } else {
+            matrix[1]
+        };
+        if dir[0] * b[1] != dir[1] * b[0] {
+            return 0;
+        }
+        let res = if ax != 0 || bx != 0 {
+            cheapest_on_line(ax, bx, b[0])
+        } else {
+            cheapest_on_line(ay, by, b[1])
+        };
+        return res.unwrap_or(0);
+    }
+
+    // aX = b, solved by Cramer's rule, which stays exact on integers even with the prizes of
+    // part two. Only consider non negative integer solutions for the number of presses
+    let a = [b[0] * by - b[1] * bx, ax * b[1] - ay * b[0]];
+    if a.iter().all(|&v| v % det == 0 && v / det >= 0) {
+        a[0] / det * 3 + a[1] / det
+    } else {
+        0
+    }
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Input<'a> = Vec<Machine>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(machines: &Self::Input<'_>) -> AoCResult {
+        let res: i64 = machines
+            .iter()
+            .map(|v| solve_linear_eq(&v.prize, &v.buttons))
+            .sum();
+        Ok(Answer::Int(res))
+    }
+
+    fn part_two(machines: &Self::Input<'_>) -> AoCResult {
+        let res: i64 = machines
+            .iter()
+            .map(|&(mut v)| {
+                v.prize[0] += 10000000000000;
+                v.prize[1] += 10000000000000;
+                solve_linear_eq(&v.prize, &v.buttons)
+            })
+            .sum();
+        Ok(Answer::Int(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_linear_eq() {
+        assert_eq!(solve_linear_eq(&[8400, 5400], &[[94, 34], [22, 67]]), 280);
+        assert_eq!(solve_linear_eq(&[12748, 12176], &[[26, 66], [67, 21]]), 0);
+        // Presses can't be negative
+        assert_eq!(solve_linear_eq(&[1, 0], &[[1, 1], [0, 1]]), 0);
+        // Buttons on the same line, where either can be cheaper per move
+        assert_eq!(solve_linear_eq(&[10, 10], &[[2, 2], [4, 4]]), 5);
+        assert_eq!(solve_linear_eq(&[9, 9], &[[3, 3], [2, 2]]), 6);
+        assert_eq!(solve_linear_eq(&[8, 8], &[[4, 4], [1, 1]]), 6);
+        assert_eq!(solve_linear_eq(&[7, 7], &[[2, 2], [4, 4]]), 0);
+        assert_eq!(solve_linear_eq(&[10, 11], &[[2, 2], [4, 4]]), 0);
+        assert_eq!(solve_linear_eq(&[0, 6], &[[0, 3], [0, 0]]), 6);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Button A: X+94, Y+34\nButton B: X+22 Y+67\nPrize: X=8400, Y=5400\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 11));
+        assert_eq!(err.expected, "\", \"");
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{
+    math, params, parse, visual, Answer, AoCError, AoCResult, Dir, Grid, ParseError, Pos, Solver,
+};
+use core::f64;
+
+use itertools::Itertools;
+
+/// Robots, with their position and velocity
+pub fn parse_input(input: &str) -> Result<Vec<(Pos, Dir)>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let (p, v) = parse::split_once(line, " ")?;
+            let (px, py) = parse::split_once(parse::strip_prefix(p, "p=")?, ",")?;
+            let (vx, vy) = parse::split_once(parse::strip_prefix(v, "v=")?, ",")?;
+            let pos = Pos {
+                x: parse::num(px)?,
+                y: parse::num(py)?,
+            };
+            let dir = Dir {
+                x: parse::num(vx)?,
+                y: parse::num(vy)?,
+            };
+            Ok((pos, dir))
+        })
+        .try_collect()
+}
+
+/// Variance of the given vector
+pub fn variance(values: &[usize]) -> f64 {
+    let mut mean = 0.0;
+    let mut squared = 0.0;
+    for &v in values {
+        mean += v as f64;
+        squared += (v * v) as f64;
+    }
+    mean /= values.len() as f64;
+    squared /= values.len() as f64;
+    squared - mean * mean
+}
+
+// Frame of the robots at the given positions, on a grid of size `limits`
+fn robots_frame(limits: Pos, positions: impl Iterator<Item = Pos>, label: String) -> visual::Frame {
+    let mut grid = Grid::new(limits, '.');
+    for pos in positions {
+        grid[pos] = '#';
+    }
+    visual::Frame::new(label, grid)
+}
+
+params! {
+    /// Size of the space the robots move on. The example is 11x7
+    pub struct Day14Params {
+        dims: Pos = Pos { x: 101, y: 103 },
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Input<'a> = (Vec<(Pos, Dir)>, Day14Params);
+    type Params = Day14Params;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Day14Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Day14Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse_input(input)?, *params))
+    }
+
+    fn part_one((robots, params): &Self::Input<'_>) -> AoCResult {
+        let limits = params.dims;
+        let mid = Pos {
+            x: limits.x / 2,
+            y: limits.y / 2,
+        };
+        let t = 100;
+
+        let res = robots
+            .iter()
+            .filter_map(|&(p, d)| {
+                let new_p = p.wrapping_add(d * t, limits);
+                if new_p.x == mid.x || new_p.y == mid.y {
+                    None
+                } else {
+                    Some((new_p.y > mid.y) as u8 * 2 + (new_p.x > mid.x) as u8)
+                }
+            })
+            .counts()
+            .iter()
+            .fold(1, |acc, (&_k, &v)| acc * v);
+
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((robots, params): &Self::Input<'_>) -> AoCResult {
+        let limits = params.dims;
+        let (limit_x, limit_y) = (limits.x, limits.y);
+
+        let mut pos_x = vec![0; robots.len()];
+        let mut pos_y = vec![0; robots.len()];
+        let (mut min_var_x, mut min_var_y) = (f64::MAX, f64::MAX);
+        let (mut min_var_t_x, mut min_var_t_y) = (0, 0);
+
+        // Find the minimum variance of points dispersion along each of the axis
+        for t in 0..limit_x.max(limit_y) {
+            for (i, &(robot_pos, robot_dir)) in robots.iter().enumerate() {
+                let pos = robot_pos.wrapping_add(robot_dir * t as isize, limits);
+                (pos_x[i], pos_y[i]) = (pos.x, pos.y);
+            }
+            visual::step(|| {
+                let positions = pos_x.iter().zip(&pos_y).map(|(&x, &y)| Pos { x, y });
+                robots_frame(limits, positions, format!("Part two, {t} seconds"))
+            });
+            let var = (variance(&pos_x), variance(&pos_y));
+            if var.0 < min_var_x {
+                min_var_x = var.0;
+                min_var_t_x = t;
+            }
+            if var.1 < min_var_y {
+                min_var_y = var.1;
+                min_var_t_y = t;
+            }
+        }
+
+        // Both axes have their minimum variance at once, solved directly by the chinese remainder
+        // theorem instead of searching the time steps
+        let (res, _) = math::crt(&[
+            (min_var_t_x as i64, limit_x as i64),
+            (min_var_t_y as i64, limit_y as i64),
+        ])
+        .ok_or_else(|| AoCError::NoSolution("The axes never align".to_string()))?;
+        visual::keyframe(|| {
+            let positions = robots
+                .iter()
+                .map(|&(pos, dir)| pos.wrapping_add(dir * res as isize, limits));
+            robots_frame(
+                limits,
+                positions,
+                format!("Part two, tree after {res} seconds"),
+            )
+        });
+
+        Ok(Answer::from(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../data/input14Test");
+
+    #[test]
+    fn test_part_one() {
+        // On the space of the example
+        let params = Day14Params {
+            dims: Pos { x: 11, y: 7 },
+        };
+        assert_eq!(Day14::solve_part_one_with(INPUT, &params).unwrap(), 12);
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{parse, visual, Answer, AoCResult, Dir, Grid, ParseError, Pos, Solver};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// Return the grid and the list of directions. The grid must be surrounded by walls and have
+/// a single robot
+pub fn parse_input(input: &str) -> Result<(Grid<char>, Vec<Dir>), ParseError> {
+    let (grid_str, directions) = parse::split_once(input.trim(), "\n\n")?;
+
+    let grid = Grid::parse_with(grid_str, "one of '#O@.'", |c| {
+        "#O@.".contains(c).then_some(c)
+    })?;
+    let mut robot = false;
+    for (y, line) in grid_str.lines().enumerate() {
+        // Cells are ASCII, so chars and bytes match
+        for (x, c) in line.char_indices() {
+            let found = &line[x..x + 1];
+            if (x == 0 || y == 0 || x == grid.width() - 1 || y == grid.height() - 1) && c != '#' {
+                return Err(ParseError::new(found, "a wall, on the border"));
+            }
+            if c == '@' {
+                if robot {
+                    return Err(ParseError::new(found, "a single robot"));
+                }
+                robot = true;
+            }
+        }
+    }
+    if !robot {
+        return Err(ParseError::new(&grid_str[grid_str.len()..], "a robot, '@'"));
+    }
+    let directions = directions
+        .lines()
+        .flat_map(|line| {
+            line.char_indices().map(|(i, c)| match c {
+                '<' => Ok(Dir::LEFT),
+                '^' => Ok(Dir::UP),
+                '>' => Ok(Dir::RIGHT),
+                'v' => Ok(Dir::DOWN),
+                _ => Err(ParseError::new(&line[i..i + c.len_utf8()], "a direction")),
+            })
+        })
+        .try_collect()?;
+    Ok((grid, directions))
+}
+
+fn find_start_pos(grid: &Grid<char>) -> Pos {
+    grid.find(&'@').expect("The robot is checked when parsing")
+}
+
+/// Sum of the GPS coordinates of the cells with the given mark
+pub fn gps_sum(grid: &Grid<char>, mark: char) -> usize {
+    grid.find_all(&mark).map(|p| 100 * p.y + p.x).sum()
+}
+
+/// Warehouse twice as wide, with the boxes as `[]`
+pub fn double_grid(grid: &Grid<char>) -> Grid<char> {
+    let transform = HashMap::from([
+        ('#', ['#', '#']),
+        ('O', ['[', ']']),
+        ('@', ['@', '.']),
+        ('.', ['.', '.']),
+    ]);
+    let data = grid
+        .iter()
+        .flat_map(|c| *transform.get(c).unwrap())
+        .collect_vec();
+
+    Grid::from_vec(
+        Pos {
+            x: grid.width() * 2,
+            y: grid.height(),
+        },
+        data,
+    )
+}
+
+// From a position gets all connected cells in the given direction
+// If a wall is found, returns None
+fn expand_frontier(grid: &Grid<char>, pos: Pos, dir: Dir) -> Option<HashSet<Pos>> {
+    let mut frontier = Vec::from([pos]);
+    let mut all = HashSet::from([pos]);
+
+    while let Some(curr) = frontier.pop() {
+        let next = curr + dir;
+        if all.contains(&next) {
+            continue;
+        }
+        let c = grid[next];
+        match c {
+            '.' => {} // Found edge
+            ']' | '[' => {
+                // Insert into frontier and all and keep going
+                frontier.push(next);
+                all.insert(next);
+                if dir.y != 0 {
+                    let other = next + if c == ']' { Dir::LEFT } else { Dir::RIGHT };
+                    frontier.push(other);
+                    all.insert(other);
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(all)
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Input<'a> = (Grid<char>, Vec<Dir>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((grid, directions): &Self::Input<'_>) -> AoCResult {
+        let mut grid = grid.clone();
+        let mut pos = find_start_pos(&grid);
+        let label = |i: usize| format!("Part one, move {i}/{}", directions.len());
+        visual::keyframe(|| visual::Frame::new(label(0), grid.clone()));
+
+        for (i, &dir) in directions.iter().enumerate() {
+            let mut next = pos + dir;
+            // Find first free space or wall
+            while grid[next] == 'O' {
+                next += dir;
+            }
+
+            if grid[next] == '.' {
+                // Move cells backwards
+                while next != pos {
+                    let prev = next - dir;
+                    grid[next] = grid[prev];
+                    next = prev;
+                }
+                grid[pos] = '.';
+                pos += dir;
+            }
+            visual::step(|| visual::Frame::new(label(i + 1), grid.clone()));
+        }
+        visual::keyframe(|| visual::Frame::new(label(directions.len()), grid.clone()));
+
+        let res = gps_sum(&grid, 'O');
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((orig_grid, directions): &Self::Input<'_>) -> AoCResult {
+        let mut grid = double_grid(orig_grid);
+        let mut scratch_grid = grid.clone();
+        let mut pos = find_start_pos(&grid);
+        let label = |i: usize| format!("Part two, move {i}/{}", directions.len());
+        visual::keyframe(|| visual::Frame::new(label(0), grid.clone()));
+
+        for (i, &d) in directions.iter().enumerate() {
+            // Move all connected cells
+            if let Some(frontier) = expand_frontier(&grid, pos, d) {
+                // Backup to scratch
+                for &p in frontier.iter() {
+                    scratch_grid[p] = grid[p];
+                }
+                // Clear
+                for &p in frontier.iter() {
+                    grid[p] = '.';
+                }
+                // Move in dir
+                for &p in frontier.iter() {
+                    grid[p + d] = scratch_grid[p];
+                }
+                pos += d;
+            }
+            visual::step(|| visual::Frame::new(label(i + 1), grid.clone()));
+        }
+        visual::keyframe(|| visual::Frame::new(label(directions.len()), grid.clone()));
+
+        let res = gps_sum(&grid, '[');
+        Ok(Answer::from(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        for (input, line, column, expected) in [
+            ("###\n#.#\n###\n\n<\n", 3, 4, "a robot, '@'"),
+            ("###\n#@#\n#@#\n###\n\n<\n", 3, 2, "a single robot"),
+            ("###\n#@.\n###\n\n<\n", 2, 3, "a wall, on the border"),
+            ("####\n#@x#\n####\n\n<\n", 2, 3, "one of '#O@.'"),
+        ] {
+            let err = parse_input(input).unwrap_err().locate(input);
+            assert_eq!((err.line, err.column), (line, column), "{input}");
+            assert_eq!(err.expected, expected);
+        }
+    }
+}
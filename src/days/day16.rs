@@ -0,0 +1,114 @@
+use crate::{search, Answer, AoCError, AoCResult, Dir, Grid, ParseError, Pos, Rotation, Solver};
+use std::collections::HashSet;
+
+/// Maze, with the start as `S` and the end as `E`
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Search node, the position and facing direction
+pub type State = (Pos, Dir);
+
+/// Moving forward costs 1, rotating in place costs 1000
+pub fn successors(&(pos, dir): &State, grid: &Grid<char>) -> Vec<(State, u64)> {
+    let mut succ = Vec::new();
+    let dirs = [
+        (dir, 1),
+        (dir.rotate(Rotation::Clockwise), 1000),
+        (dir.rotate(Rotation::CounterClockwise), 1000),
+    ];
+    for (next_dir, cost) in dirs {
+        // Outside of the grid counts as a wall, for mazes without a border
+        let Some(next_pos) = pos.checked_add(next_dir, grid.dims) else {
+            continue;
+        };
+        if grid[next_pos] != '#' {
+            let next_pos = if next_dir == dir { next_pos } else { pos };
+            succ.push(((next_pos, next_dir), cost));
+        }
+    }
+
+    succ
+}
+
+/// Returns the cost of the best paths and the number of positions on any of them
+pub fn solve(grid: &Grid<char>) -> Result<(u64, u64), AoCError> {
+    let start = grid
+        .find(&'S')
+        .ok_or_else(|| AoCError::Input("Couldn't find S".to_string()))?;
+    let start = (start, Dir::RIGHT);
+
+    let res = search::dijkstra_all(
+        start,
+        |state| successors(state, grid),
+        |&(pos, _)| grid[pos] == 'E',
+    );
+    let cost = res
+        .goal_cost()
+        .ok_or_else(|| AoCError::NoSolution("Couldn't find a path".to_string()))?;
+    // Unique positions on all the best paths
+    let unique_pos = res
+        .path_nodes(&res.goals)
+        .iter()
+        .map(|&(pos, _)| pos)
+        .collect::<HashSet<_>>();
+
+    Ok((cost, unique_pos.len() as u64))
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Input<'a> = Grid<char>;
+    type Params = ();
+    // Both parts come from the same search
+    const SHARED: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> AoCResult {
+        Ok(Answer::from(solve(grid)?.0))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> AoCResult {
+        Ok(Answer::from(solve(grid)?.1))
+    }
+
+    fn solve_both(grid: &Self::Input<'_>) -> (AoCResult, AoCResult) {
+        match solve(grid) {
+            Ok((cost, positions)) => (Ok(Answer::from(cost)), Ok(Answer::from(positions))),
+            Err(err) => (Err(err.clone()), Err(err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        include_str!("../../data/input16Test"),
+        include_str!("../../data/input16"),
+    ];
+    const EXPECTED_PART_ONE: [u64; 2] = [11048, 135536];
+    const EXPECTED_PART_TWO: [u64; 2] = [64, 583];
+
+    #[test]
+    fn test_solve_both() {
+        // Each call is solved for its own input
+        for i in [1, 0] {
+            let (one, two) = Day16::solve_both(&Day16::parse(INPUT[i]).unwrap());
+            assert_eq!(one.unwrap(), EXPECTED_PART_ONE[i]);
+            assert_eq!(two.unwrap(), EXPECTED_PART_TWO[i]);
+        }
+    }
+
+    #[test]
+    fn test_no_border() {
+        assert_eq!(Day16::solve_part_one("S.E\n").unwrap(), 2);
+        assert_eq!(Day16::solve_part_two("S.E\n").unwrap(), 3);
+        assert!(Day16::solve_part_one("S#E\n").is_err());
+    }
+}
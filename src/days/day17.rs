@@ -0,0 +1,283 @@
+use crate::{parse, Answer, AoCError, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+
+/// Registers A, B and C
+pub type Regs = [u64; 3];
+
+// Opcodes whose operand is a combo operand
+fn takes_combo(opcode: u8) -> bool {
+    matches!(opcode, 0 | 2 | 5 | 6 | 7)
+}
+
+/// Initial registers, and the program, validated so that it can be run
+pub fn parse_input(input: &str) -> Result<(Regs, Vec<u8>), ParseError> {
+    let mut regs = [0, 0, 0];
+    let mut program = Vec::new();
+    let reg_map = [
+        ("Register A: ", 0usize),
+        ("Register B: ", 1usize),
+        ("Register C: ", 2usize),
+    ];
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        if let Some(&(pat, idx)) = reg_map.iter().find(|(pat, _)| line.starts_with(pat)) {
+            regs[idx] = parse::num(&line[pat.len()..])?;
+        } else {
+            let values = parse::strip_prefix(line, "Program: ")?;
+            program = Vec::new();
+            for token in values.split(",") {
+                let v = parse::num::<u8>(token)?;
+                let expected = match program.len() % 2 {
+                    0 if v > 7 => Some("an opcode, from 0 to 7"),
+                    1 if takes_combo(program[program.len() - 1]) && v > 6 => {
+                        Some("a combo operand, from 0 to 6")
+                    }
+                    1 if v > 7 => Some("an operand, from 0 to 7"),
+                    _ => None,
+                };
+                if let Some(expected) = expected {
+                    return Err(ParseError::new(token, expected));
+                }
+                program.push(v);
+            }
+            if program.len() % 2 == 1 {
+                return Err(ParseError::new(
+                    &values[values.len()..],
+                    "an operand after the last opcode",
+                ));
+            }
+        }
+    }
+
+    Ok((regs, program))
+}
+
+fn combo_op(op: u8, regs: &Regs) -> u64 {
+    match op {
+        0..=3 => op as u64,
+        4 => regs[0],
+        5 => regs[1],
+        6 => regs[2],
+        _ => panic!("Invalid combo operand {op}"),
+    }
+}
+
+// Division of A by 2 to the power of the combo operand
+fn div(op: u8, regs: &Regs) -> u64 {
+    let shift = combo_op(op, regs);
+    regs[0]
+        .checked_shr(shift.try_into().unwrap_or(u32::MAX))
+        .unwrap_or(0)
+}
+
+/// Runs the program from the registers, returning its output. It halts when an opcode or
+/// operand would be read past its end, and panics on the invalid opcodes and operands that
+/// `parse_input` rejects
+pub fn run(regs: &mut Regs, instruct: &[u8]) -> Vec<u64> {
+    let mut ip = 0usize;
+    let mut out = Vec::new();
+
+    while ip + 1 < instruct.len() {
+        let inst = instruct[ip];
+        let operand = instruct[ip + 1];
+        match inst {
+            0 => {
+                regs[0] = div(operand, regs);
+            }
+            1 => {
+                regs[1] ^= operand as u64;
+            }
+            2 => {
+                regs[1] = combo_op(operand, regs) % 8;
+            }
+            3 => {
+                if regs[0] != 0 {
+                    ip = operand as usize;
+                    continue;
+                }
+            }
+            4 => {
+                regs[1] ^= regs[2];
+            }
+            5 => {
+                out.push(combo_op(operand, regs) % 8);
+            }
+            6 => {
+                regs[1] = div(operand, regs);
+            }
+            7 => {
+                regs[2] = div(operand, regs);
+            }
+            _ => {
+                panic!("Invalid opcode {inst}");
+            }
+        }
+        ip += 2;
+    }
+    out
+}
+
+fn run_part_two(a: u64, instruct: &[u8]) -> Option<u64> {
+    // Directly interpreting the program
+    // Remove the last JNZ instruction, so that it only executes once
+    let mut regs = [a, 0, 0];
+    let out = run(&mut regs, &instruct[0..instruct.len() - 2]);
+    out.first().copied()
+}
+
+/// Lowest value of register A for which the program outputs itself, given the first output
+/// of the program from each value of A, if any. Each output shifts A right by 3 bits
+pub fn find_quine(instruct: &[u8], first_output: impl Fn(u64) -> Option<u64>) -> AoCResult {
+    let mut valid = vec![0u64];
+    // Generate each digit in turn
+    for &v in instruct.iter().rev() {
+        // There can be several solutions, try all and save new valid ones
+        let mut next_valid = Vec::new();
+        while let Some(curr) = valid.pop() {
+            for d in 0..8 {
+                // Next digit can only be at curr..curr+8, try each one
+                let a = curr + d;
+                if first_output(a) == Some(v as u64) {
+                    next_valid.push(a * 8);
+                }
+            }
+        }
+        valid = next_valid;
+        if valid.is_empty() {
+            return Err(AoCError::NoSolution(format!("Couldn't find match for {v}")));
+        }
+    }
+
+    let res = *valid.iter().min().unwrap() / 8;
+    Ok(Answer::from(res))
+}
+
+/// Decoded program, to check the interpreter against. Only for programs of the same shape as
+/// the real inputs, which output a function of A and shift it right by 3 bits until it is 0
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use super::*;
+
+    /// First output of the program from register A
+    pub fn decoded(a: u64, instruct: &[u8]) -> u64 {
+        // Operands of the two XORs with a literal, in order
+        let mut xors = instruct
+            .chunks(2)
+            .filter(|inst| inst[0] == 1)
+            .map(|inst| inst[1] as u64);
+        let (xor1, xor2) = (xors.next().unwrap_or(0), xors.next().unwrap_or(0));
+        let mut b = (a % 8) ^ xor1;
+        let c = a >> b;
+        b = b ^ xor2 ^ c;
+        b % 8
+    }
+
+    /// Answers of both parts
+    pub fn solve(input: &str) -> Result<[AoCResult; 2], ParseError> {
+        let (regs, instruct) = parse_input(input)?;
+        let mut a = regs[0];
+        let mut out = vec![decoded(a, &instruct)];
+        while a >= 8 {
+            a >>= 3;
+            out.push(decoded(a, &instruct));
+        }
+        Ok([
+            Ok(Answer::Str(out.iter().join(","))),
+            find_quine(&instruct, |a| Some(decoded(a, &instruct))),
+        ])
+    }
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Input<'a> = (Regs, Vec<u8>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((regs, instruct): &Self::Input<'_>) -> AoCResult {
+        let mut regs = *regs;
+
+        let out = run(&mut regs, instruct);
+        let res = out.iter().map(|v| v.to_string()).join(",");
+        Ok(Answer::Str(res))
+    }
+
+    fn part_two((_regs, instruct): &Self::Input<'_>) -> AoCResult {
+        if !instruct.ends_with(&[3, 0]) {
+            let msg = "The program must end by jumping back to the start";
+            return Err(AoCError::Input(msg.to_string()));
+        }
+        find_quine(instruct, |a| run_part_two(a, instruct))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        include_str!("../../data/input17Test"),
+        include_str!("../../data/input17"),
+    ];
+    const EXPECTED_PART_ONE: [&str; 2] = ["5,7,3,0", "7,6,5,3,6,5,7,0,4"];
+    const EXPECTED_PART_TWO: [u64; 2] = [117440, 190615597431823];
+
+    #[test]
+    fn test_part_one() {
+        let input1 = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5,4,3,0\n";
+        assert_eq!(
+            Day17::solve_part_one(input1).unwrap(),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
+
+    #[test]
+    fn test_reference() {
+        let [one, two] = reference::solve(INPUT[1]).unwrap();
+        assert_eq!(one.unwrap(), EXPECTED_PART_ONE[1]);
+        assert_eq!(two.unwrap(), EXPECTED_PART_TWO[1]);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,x,4\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column, err.found.as_str()), (5, 14, "x"));
+
+        for (program, column, expected) in [
+            ("0,7", 12, "a combo operand, from 0 to 6"),
+            ("1,7,8,1", 14, "an opcode, from 0 to 7"),
+            ("4,8", 12, "an operand, from 0 to 7"),
+            ("0", 11, "an operand after the last opcode"),
+        ] {
+            let input = format!("Register A: 1\n\nProgram: {program}\n");
+            let err = parse_input(&input).unwrap_err().locate(&input);
+            assert_eq!((err.line, err.column), (3, column), "{program}");
+            assert_eq!(err.expected, expected);
+        }
+    }
+
+    #[test]
+    fn test_invalid_programs() {
+        // Jumping to the last operand halts, instead of reading past the end
+        let input = "Register A: 1\n\nProgram: 5,4,3,3\n";
+        assert_eq!(Day17::solve_part_one(input).unwrap(), "1");
+        assert!(matches!(
+            Day17::solve_part_two(input),
+            Err(AoCError::Input(_))
+        ));
+        // Without an output there's no quine
+        let input = "Register A: 1\n\nProgram: 0,3,3,0\n";
+        assert!(matches!(
+            Day17::solve_part_two(input),
+            Err(AoCError::NoSolution(_))
+        ));
+        // Shifts past the width of the registers
+        let input = "Register A: 1\nRegister B: 100\n\nProgram: 0,5,5,4\n";
+        assert_eq!(Day17::solve_part_one(input).unwrap(), "0");
+    }
+}
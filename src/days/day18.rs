@@ -0,0 +1,220 @@
+use crate::{
+    params, parse, search, visual, Answer, AoCError, AoCResult, Grid, ParseError, Pos, Solver,
+};
+use itertools::Itertools;
+
+/// Positions of the falling bytes, in order
+pub fn parse_input(input: &str) -> Result<Vec<Pos>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (x, y) = parse::split_once(line, ",")?;
+            Ok(Pos {
+                x: parse::num(x)?,
+                y: parse::num(y)?,
+            })
+        })
+        .try_collect()
+}
+
+/// Search from the top left to the bottom right corner, only going through positions that
+/// aren't corrupted before `cutoff_time`
+pub fn search_exit(grid: &Grid<usize>, cutoff_time: usize) -> search::SearchResult<Pos, usize> {
+    let goal = Pos {
+        x: grid.width() - 1,
+        y: grid.height() - 1,
+    };
+    search::bfs(
+        Pos { x: 0, y: 0 },
+        |&p| grid.neighbors(p).filter(|&n| grid[n] >= cutoff_time),
+        |&p| p == goal,
+    )
+}
+
+/// Length of the shortest path to the exit, with the positions corrupted before `cutoff_time`
+pub fn shortest_path(grid: &Grid<usize>, cutoff_time: usize) -> Option<usize> {
+    search_exit(grid, cutoff_time).goal_cost()
+}
+
+// Frame of the positions corrupted before `cutoff_time`, and of a shortest path to the exit
+fn path_frame(grid: &Grid<usize>, cutoff_time: usize, label: String) -> visual::Frame {
+    let res = search_exit(grid, cutoff_time);
+    let mut frame = grid.map(|&t| if t < cutoff_time { '#' } else { '.' });
+    for pos in res
+        .goal()
+        .and_then(|goal| res.path(goal))
+        .unwrap_or_default()
+    {
+        frame[pos] = 'o';
+    }
+    visual::Frame::new(label, frame)
+}
+
+/// Grid of size `dims` with the time each position is corrupted at, or `usize::MAX`
+pub fn build_grid(dims: Pos, positions: &[Pos]) -> Grid<usize> {
+    let mut grid = Grid::new(dims, usize::MAX);
+
+    for (i, &pos) in positions.iter().enumerate() {
+        if grid[pos] > i {
+            grid[pos] = i;
+        }
+    }
+    grid
+}
+
+params! {
+    /// Size of the memory space, and bytes fallen on it for part one. The example is 7x7, after
+    /// 12 bytes
+    pub struct Day18Params {
+        dims: Pos = Pos { x: 71, y: 71 },
+        cutoff_time: usize = 1024,
+    }
+}
+
+fn min_steps(grid: &Grid<usize>, cutoff_time: usize) -> AoCResult {
+    let res = shortest_path(grid, cutoff_time)
+        .ok_or_else(|| AoCError::NoSolution("Couldn't find a path".to_string()))?;
+    visual::keyframe(|| {
+        let label = format!("Part one, path of {res} steps after {cutoff_time} bytes");
+        path_frame(grid, cutoff_time, label)
+    });
+    Ok(Answer::from(res))
+}
+
+// First corrupted position that blocks every path to the exit
+fn first_blocking(grid: &Grid<usize>, corrupted: &[Pos]) -> AoCResult {
+    if shortest_path(grid, corrupted.len()).is_some() {
+        let msg = "The exit is still reachable after all the bytes".to_string();
+        return Err(AoCError::NoSolution(msg));
+    }
+    // Binary search on time
+    let mut min_time = 0;
+    let mut max_time = corrupted.len();
+    while max_time - min_time > 1 {
+        let half_time = min_time + (max_time - min_time) / 2;
+        visual::step(|| path_frame(grid, half_time, format!("Part two, {half_time} bytes")));
+        if shortest_path(grid, half_time).is_none() {
+            max_time = half_time;
+        } else {
+            min_time = half_time;
+        }
+    }
+    let limit_time = min_time;
+    visual::keyframe(|| {
+        let bytes = limit_time + 1;
+        let label = format!("Part two, blocked after {bytes} bytes");
+        let mut frame = path_frame(grid, bytes, label);
+        frame.grid[corrupted[limit_time]] = '@';
+        frame
+    });
+    let res = format!("{},{}", corrupted[limit_time].x, corrupted[limit_time].y);
+    Ok(Answer::Str(res))
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Input<'a> = (Vec<Pos>, Day18Params);
+    type Params = Day18Params;
+    // Both parts search on the same grid
+    const SHARED: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Day18Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Day18Params) -> Result<Self::Input<'a>, ParseError> {
+        let corrupted = parse_input(input)?;
+        // Bytes must fall inside the memory space, the lines match the positions parsed
+        let dims = params.dims;
+        if let Some((line, _)) = input
+            .trim()
+            .lines()
+            .zip(&corrupted)
+            .find(|(_, pos)| pos.x >= dims.x || pos.y >= dims.y)
+        {
+            let expected = format!("a position inside {}x{}", dims.x, dims.y);
+            return Err(ParseError::new(line, expected));
+        }
+        Ok((corrupted, *params))
+    }
+
+    fn part_one((corrupted, params): &Self::Input<'_>) -> AoCResult {
+        min_steps(&build_grid(params.dims, corrupted), params.cutoff_time)
+    }
+
+    fn part_two((corrupted, params): &Self::Input<'_>) -> AoCResult {
+        first_blocking(&build_grid(params.dims, corrupted), corrupted)
+    }
+
+    fn solve_both((corrupted, params): &Self::Input<'_>) -> (AoCResult, AoCResult) {
+        let grid = build_grid(params.dims, corrupted);
+        (
+            min_steps(&grid, params.cutoff_time),
+            first_blocking(&grid, corrupted),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        include_str!("../../data/input18Test"),
+        include_str!("../../data/input18"),
+    ];
+    const EXPECTED_PART_ONE: [i64; 2] = [22, 286];
+    const EXPECTED_PART_TWO: [&str; 2] = ["6,1", "20,64"];
+    // The example is on a smaller memory space
+    const TEST_PARAMS: Day18Params = Day18Params {
+        dims: Pos { x: 7, y: 7 },
+        cutoff_time: 12,
+    };
+
+    #[test]
+    fn test_part_one() {
+        let corrupted = parse_input(INPUT[0]).unwrap();
+        let grid = build_grid(TEST_PARAMS.dims, &corrupted);
+        let res = shortest_path(&grid, TEST_PARAMS.cutoff_time).expect("Couldn't find a path");
+        assert_eq!(res as i64, EXPECTED_PART_ONE[0]);
+
+        assert_eq!(
+            Day18::solve_part_one_with(INPUT[0], &TEST_PARAMS).unwrap(),
+            EXPECTED_PART_ONE[0]
+        );
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(
+            Day18::solve_part_two_with(INPUT[0], &TEST_PARAMS).unwrap(),
+            EXPECTED_PART_TWO[0]
+        );
+    }
+
+    #[test]
+    fn test_solve_both() {
+        let (one, two) = Day18::solve_both(&Day18::parse(INPUT[1]).unwrap());
+        assert_eq!(one.unwrap(), EXPECTED_PART_ONE[1]);
+        assert_eq!(two.unwrap(), EXPECTED_PART_TWO[1]);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        // Bytes outside of the memory space
+        let err = Day18::parse_with(INPUT[1], &TEST_PARAMS).unwrap_err();
+        assert_eq!(err.expected, "a position inside 7x7");
+        let input = "0,0\n8,0\n";
+        let err = Day18::parse_with(input, &TEST_PARAMS)
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((err.line, err.found.as_str()), (2, "8,0"));
+
+        assert!(matches!(
+            Day18::solve_part_two_with("1,1\n", &TEST_PARAMS),
+            Err(AoCError::NoSolution(_))
+        ));
+    }
+}
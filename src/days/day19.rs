@@ -0,0 +1,134 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+
+/// Towel patterns, and the designs to make
+pub fn parse_input(input: &str) -> Result<(Vec<&str>, Vec<&str>), ParseError> {
+    let (patterns, designs) = parse::split_once(input.trim(), "\n\n")?;
+    Ok((
+        patterns.split(", ").collect_vec(),
+        designs.lines().collect_vec(),
+    ))
+}
+
+/// Naive solution, to check the optimized one against
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Ways to make `design` with the patterns, memoised on the rest of the design
+    pub fn possible_combinations<'a>(
+        design: &'a str,
+        patterns: &[&str],
+        memo: &mut HashMap<&'a str, u64>,
+    ) -> u64 {
+        if design.is_empty() {
+            return 1;
+        }
+        if let Some(&count) = memo.get(design) {
+            return count;
+        }
+        let res = patterns
+            .iter()
+            .filter(|pat| design.starts_with(*pat))
+            .map(|pat| possible_combinations(&design[pat.len()..], patterns, memo))
+            .sum();
+        memo.insert(design, res);
+        res
+    }
+
+    /// Answers of both parts
+    pub fn solve(input: &str) -> Result<[AoCResult; 2], ParseError> {
+        let (patterns, designs) = parse_input(input)?;
+        let mut memo = HashMap::new();
+        let counts = designs
+            .iter()
+            .map(|design| possible_combinations(design, &patterns, &mut memo))
+            .collect_vec();
+        let possible = counts.iter().filter(|&&count| count > 0).count();
+        Ok([
+            Ok(Answer::from(possible)),
+            Ok(Answer::from(counts.iter().sum::<u64>())),
+        ])
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((patterns, designs): &Self::Input<'_>) -> AoCResult {
+        let res = designs
+            .iter()
+            .map(|&design| {
+                let mut reached_idx = Vec::from([0usize]);
+                // Each index is only explored once, designs that can't be made would
+                // otherwise be tried in every way
+                let mut seen = vec![false; design.len()];
+                while let Some(idx) = reached_idx.pop() {
+                    for pat in patterns {
+                        if design[idx..].starts_with(pat) {
+                            let new_idx = idx + pat.len();
+                            if new_idx == design.len() {
+                                return 1;
+                            } else if !seen[new_idx] {
+                                seen[new_idx] = true;
+                                reached_idx.push(new_idx);
+                            }
+                        }
+                    }
+                }
+                0
+            })
+            .sum::<i64>();
+
+        Ok(Answer::Int(res))
+    }
+
+    fn part_two((patterns, designs): &Self::Input<'_>) -> AoCResult {
+        let res = designs
+            .iter()
+            .map(|design| {
+                let mut paths_to_idx = vec![0u64; design.len() + 1];
+                paths_to_idx[0] = 1;
+                for idx in 0..design.len() {
+                    for pat in patterns {
+                        if design[idx..].starts_with(pat) {
+                            let new_idx = idx + pat.len();
+                            paths_to_idx[new_idx] += paths_to_idx[idx];
+                        }
+                    }
+                }
+                *paths_to_idx.last().unwrap()
+            })
+            .sum::<u64>();
+        Ok(Answer::from(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        include_str!("../../data/input19Test"),
+        include_str!("../../data/input19"),
+    ];
+    const EXPECTED_PART_ONE: [i64; 2] = [6, 228];
+    const EXPECTED_PART_TWO: [u64; 2] = [16, 584553405070389];
+
+    #[test]
+    fn test_reference() {
+        for i in 0..2 {
+            let [one, two] = reference::solve(INPUT[i]).unwrap();
+            assert_eq!(one.unwrap(), EXPECTED_PART_ONE[i]);
+            assert_eq!(two.unwrap(), EXPECTED_PART_TWO[i]);
+        }
+    }
+}
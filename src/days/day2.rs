@@ -0,0 +1,79 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+
+/// Reports, as lists of levels
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    input
+        .trim()
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split_whitespace().map(parse::num).try_collect())
+        .try_collect()
+}
+
+/// Whether the levels all increase or all decrease, by 1 to 3 at each step
+pub fn valid_levels(levels: &[i32]) -> bool {
+    let diff = levels.windows(2).map(|v| v[1] - v[0]).collect_vec();
+    diff.iter().all(|&v| (1..=3).contains(&v)) || diff.iter().all(|&v| (-3..=-1).contains(&v))
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Input<'a> = Vec<Vec<i32>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(reports: &Self::Input<'_>) -> AoCResult {
+        let res = reports
+            .iter()
+            .map(|levels| valid_levels(levels) as i32)
+            .sum::<i32>();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two(reports: &Self::Input<'_>) -> AoCResult {
+        let res = reports
+            .iter()
+            .map(|levels| {
+                if valid_levels(levels) {
+                    return 1;
+                }
+
+                let diff = levels.windows(2).map(|v| v[1] - v[0]).collect_vec();
+                // Index of first ascending sequence break
+                let asc_break_idx = diff
+                    .iter()
+                    .map(|&v| (1..=3).contains(&v) as u8)
+                    .position(|v| v == 0)
+                    .unwrap_or(0);
+                // Index of first descending sequence break
+                let dsc_break_idx = diff
+                    .iter()
+                    .map(|&v| (-3..=-1).contains(&v) as u8)
+                    .position(|v| v == 0)
+                    .unwrap_or(0);
+
+                // Remove the first asc/desc sequence break or its successor and check if we get a valid levels
+                for idx in [
+                    asc_break_idx,
+                    asc_break_idx + 1,
+                    dsc_break_idx,
+                    dsc_break_idx + 1,
+                ] {
+                    let mut new_levels = levels.clone();
+                    new_levels.remove(idx);
+                    if valid_levels(&new_levels) {
+                        return 1;
+                    }
+                }
+                0
+            })
+            .sum();
+
+        Ok(Answer::Int(res))
+    }
+}
@@ -0,0 +1,222 @@
+use crate::{params, search, Answer, AoCError, AoCResult, Grid, ParseError, Pos, Solver};
+
+/// Racetrack, with the start as `S` and the end as `E`
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// The single path from start to end
+pub fn find_path(grid: &Grid<char>, start: char, end: char) -> Result<Vec<Pos>, AoCError> {
+    let start_pos = grid
+        .find(&start)
+        .ok_or_else(|| AoCError::Input(format!("Couldn't find {start}")))?;
+    let res = search::bfs(
+        start_pos,
+        |&p| grid.neighbors(p).filter(|&n| grid[n] != '#'),
+        |&p| grid[p] == end,
+    );
+    res.goal()
+        .and_then(|goal| res.path(goal))
+        .ok_or_else(|| AoCError::NoSolution("Couldn't find a path".to_string()))
+}
+
+fn build_grid_path(grid: &Grid<char>, path: &[Pos]) -> Grid<usize> {
+    let mut out = Grid::new(grid.dims, usize::MAX);
+    for (i, &p) in path.iter().enumerate() {
+        out[p] = i;
+    }
+    out
+}
+
+fn clamp(center: usize, spread: usize, min: usize, max: usize) -> (usize, usize) {
+    let start = if center >= spread + min {
+        center - spread
+    } else {
+        min
+    };
+    let end = if center + spread < max {
+        center + spread + 1
+    } else {
+        max
+    };
+    (start, end)
+}
+
+/// Cheats of up to `max_cheat_len` picoseconds along the path that save at least `min_save`
+pub fn count_cheats(
+    grid: &Grid<char>,
+    path: &[Pos],
+    max_cheat_len: usize,
+    min_save: usize,
+) -> usize {
+    // No cheat can save more than the length of the path
+    if min_save >= path.len() {
+        return 0;
+    }
+
+    let grid_path = build_grid_path(grid, path);
+    let mut res = 0;
+    for start in &path[0..path.len() - min_save] {
+        let i_start = grid_path[*start];
+        let (start_x, end_x) = clamp(start.x, max_cheat_len, 0, grid_path.width());
+        let (start_y, end_y) = clamp(start.y, max_cheat_len, 0, grid_path.height());
+
+        for y in start_y..end_y {
+            for x in start_x..end_x {
+                let save = grid_path[Pos { y, x }];
+                if save == usize::MAX {
+                    continue;
+                }
+                let dist = manhattan_dist(start, &Pos { y, x });
+                if dist <= max_cheat_len
+                    && save > i_start + dist
+                    && save - i_start - dist >= min_save
+                {
+                    res += 1;
+                }
+            }
+        }
+    }
+    res
+}
+
+params! {
+    /// Picoseconds a cheat must save to be counted. The example lists cheats saving any time
+    pub struct Day20Params {
+        min_save: usize = 100,
+    }
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Input<'a> = (Grid<char>, Day20Params);
+    type Params = Day20Params;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Day20Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Day20Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse_input(input)?, *params))
+    }
+
+    fn part_one((grid, params): &Self::Input<'_>) -> AoCResult {
+        let path = find_path(grid, 'S', 'E')?;
+        let res = count_cheats(grid, &path, 2, params.min_save);
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((grid, params): &Self::Input<'_>) -> AoCResult {
+        let path = find_path(grid, 'S', 'E')?;
+        let res = count_cheats(grid, &path, 20, params.min_save);
+        Ok(Answer::from(res))
+    }
+}
+
+fn manhattan_dist(p1: &Pos, p2: &Pos) -> usize {
+    p1.x.abs_diff(p2.x) + p1.y.abs_diff(p2.y)
+}
+
+/// Naive solution, to check the optimized one against
+#[cfg(any(test, feature = "reference"))]
+pub mod reference {
+    use super::*;
+
+    /// Cheats of up to `max_cheat_len` picoseconds that save at least `min_save`, trying every
+    /// pair of positions far enough along the path
+    pub fn count_cheats(path: &[Pos], max_cheat_len: usize, min_save: usize) -> usize {
+        if min_save >= path.len() {
+            return 0;
+        }
+
+        let mut res = 0;
+        for (i_start, start) in path[0..path.len() - min_save].iter().enumerate() {
+            for (i_end, end) in path[i_start + min_save..].iter().enumerate() {
+                let dist = manhattan_dist(start, end);
+                if dist <= max_cheat_len {
+                    // The distance is never longer than the path between them
+                    let save = i_end + min_save - dist;
+                    if save >= min_save {
+                        res += 1;
+                    }
+                }
+            }
+        }
+        res
+    }
+
+    /// Answers of both parts
+    pub fn solve(input: &str, params: &Day20Params) -> Result<[AoCResult; 2], ParseError> {
+        let grid = parse_input(input)?;
+        let path = find_path(&grid, 'S', 'E');
+        Ok([2, 20].map(|max_cheat_len| {
+            let path = path.as_ref().map_err(Clone::clone)?;
+            let res = count_cheats(path, max_cheat_len, params.min_save);
+            Ok(Answer::from(res))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: [&str; 2] = [
+        include_str!("../../data/input20Test"),
+        include_str!("../../data/input20"),
+    ];
+    const EXPECTED_PART_ONE: [i64; 2] = [0, 1296];
+    const EXPECTED_PART_TWO: [i64; 2] = [0, 977665];
+
+    #[test]
+    fn test_part_one() {
+        let grid = parse_input(INPUT[0]).unwrap();
+        let path = find_path(&grid, 'S', 'E').unwrap();
+        let res = count_cheats(&grid, &path, 2, 64);
+        assert_eq!(res, 1);
+        let res = count_cheats(&grid, &path, 2, 38);
+        assert_eq!(res, 3);
+        let res = count_cheats(&grid, &path, 2, 10);
+        assert_eq!(res, 10);
+        let params = Day20Params { min_save: 38 };
+        assert_eq!(Day20::solve_part_one_with(INPUT[0], &params).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let grid = parse_input(INPUT[0]).unwrap();
+        let path = find_path(&grid, 'S', 'E').unwrap();
+        let res = count_cheats(&grid, &path, 20, 76);
+        assert_eq!(res, 3);
+        let res = count_cheats(&grid, &path, 20, 68);
+        assert_eq!(res, 55);
+        let params = Day20Params { min_save: 76 };
+        assert_eq!(Day20::solve_part_two_with(INPUT[0], &params).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_reference() {
+        let grid = parse_input(INPUT[0]).unwrap();
+        let path = find_path(&grid, 'S', 'E').unwrap();
+        assert_eq!(reference::count_cheats(&path, 2, 38), 3);
+        assert_eq!(reference::count_cheats(&path, 20, 68), 55);
+        let [one, two] = reference::solve(INPUT[1], &Day20Params::default()).unwrap();
+        assert_eq!(one.unwrap(), EXPECTED_PART_ONE[1]);
+        assert_eq!(two.unwrap(), EXPECTED_PART_TWO[1]);
+    }
+
+    #[test]
+    fn test_invalid_track() {
+        assert!(matches!(
+            Day20::solve_part_one("#.E\n"),
+            Err(AoCError::Input(_))
+        ));
+        assert!(matches!(
+            Day20::solve_part_two("S#E\n"),
+            Err(AoCError::NoSolution(_))
+        ));
+        let [one, _] = reference::solve("S#E\n", &Day20Params::default()).unwrap();
+        assert!(one.is_err());
+    }
+}
@@ -1,27 +1,37 @@
-use aoc2024::*;
+use crate::{params, parse, stats, Answer, AoCResult, Dir, ParseError, Solver};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
 
-fn parse_input(input: &str) -> Vec<&str> {
-    input.lines().filter(|line| !line.is_empty()).collect_vec()
+/// Returns each code and its numeric part
+pub fn parse_input(input: &str) -> Result<Vec<(&str, usize)>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            let digits = line
+                .strip_suffix('A')
+                .ok_or_else(|| ParseError::new(line, "a code ending in 'A'"))?;
+            Ok((line, parse::num(digits)?))
+        })
+        .try_collect()
 }
 
 fn dir_to_moves(dir: &Dir) -> String {
     let horiz = match dir.x.signum() {
-        -1 => "<".repeat(dir.x.abs() as usize),
-        1 => ">".repeat(dir.x.abs() as usize),
+        -1 => "<".repeat(dir.x.unsigned_abs()),
+        1 => ">".repeat(dir.x.unsigned_abs()),
         _ => String::new(),
     };
     let vert = match dir.y.signum() {
-        -1 => "^".repeat(dir.y.abs() as usize),
-        1 => "v".repeat(dir.y.abs() as usize),
+        -1 => "^".repeat(dir.y.unsigned_abs()),
+        1 => "v".repeat(dir.y.unsigned_abs()),
         _ => String::new(),
     };
     horiz + &vert
 }
 
 fn char_combinations(v: String) -> HashSet<String> {
-    if v.len() == 0 {
+    if v.is_empty() {
         HashSet::from([v])
     } else {
         (0..v.len())
@@ -82,8 +92,10 @@ fn expand_rec<'a>(
     // Already memoized?
     let memo_key = (single_move, depth);
     if memo.contains_key(&memo_key) {
+        stats::count("memo hits");
         return memo[&memo_key];
     }
+    stats::count("memo misses");
 
     // Expand
     let expansions = expansion_map
@@ -115,8 +127,12 @@ fn expand_rec<'a>(
     min_expansion
 }
 
-// Returns the minimum length of the expansion of moves to the specified depth
-fn expand_moves(moves: &str, depth: u8) -> usize {
+/// Returns the minimum length of the expansion of moves to the specified depth
+pub fn expand_moves(moves: &str, depth: u8) -> usize {
+    if depth == 0 {
+        // Typed directly, without any keypad in between
+        return moves.len();
+    }
     // The expansion of each possible move through the keypad, including the initial "A"
     let expansion_map: HashMap<&str, Vec<&str>> = HashMap::from([
         ("A^", vec!["A<A"]),
@@ -186,66 +202,76 @@ fn num_keypads() -> (HashMap<char, Dir>, HashMap<Dir, char>) {
 //     (keypad, keypad_inverted)
 // }
 
-pub fn solve(input: &str, depth: u8) -> AoCResult {
-    let codes = parse_input(input);
+/// Sum of the complexities of the codes, typed through `depth` directional keypads
+pub fn solve(codes: &[(&str, usize)], depth: u8) -> AoCResult {
     let (num_keypad, num_keypad_inv) = num_keypads();
 
-    let res = codes.iter().fold(0, |acc, code| {
+    let res = codes.iter().fold(0, |acc, &(code, n)| {
         let mut min_len = usize::MAX;
 
         for robot_num in keypad_sim(&num_keypad, &num_keypad_inv, Dir { y: 3, x: 2 }, code) {
             let robot_dir = expand_moves(&robot_num, depth);
             min_len = min_len.min(robot_dir);
         }
-        let n = code
-            .chars()
-            .filter(|c| c.is_digit(10))
-            .collect::<String>()
-            .parse::<usize>()
-            .unwrap();
         acc + min_len * n
     });
-    AoCResult::Int(res as i64)
+    Ok(Answer::from(res))
 }
 
-pub fn solve_part_one(input: &str) -> AoCResult {
-    solve(input, 2)
+params! {
+    /// Directional keypads operated by robots on each part
+    pub struct Day21Params {
+        depth_one: u8 = 2,
+        depth_two: u8 = 25,
+    }
 }
 
-pub fn solve_part_two(input: &str) -> AoCResult {
-    solve(input, 25)
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Input<'a> = (Vec<(&'a str, usize)>, Day21Params);
+    type Params = Day21Params;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Day21Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Day21Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse_input(input)?, *params))
+    }
+
+    fn part_one((codes, params): &Self::Input<'_>) -> AoCResult {
+        solve(codes, params.depth_one)
+    }
+
+    fn part_two((codes, params): &Self::Input<'_>) -> AoCResult {
+        solve(codes, params.depth_two)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: [&str; 2] = [
-        include_str!("../data/input21Test"),
-        include_str!("../data/input21"),
-    ];
-    const EXPECTED_PART_ONE: [i64; 2] = [126384, 215374];
-    const EXPECTED_PART_TWO: [i64; 2] = [154115708116294, 260586897262600];
+    const INPUT: &str = include_str!("../../data/input21Test");
 
     #[test]
-    fn test_part_one() {
-        for i in 0..2 {
-            let res = solve_part_one(INPUT[i]);
-            match res {
-                AoCResult::Int(v) => assert_eq!(v, EXPECTED_PART_ONE[i]),
-                _ => panic!("Wrong result type returned"),
-            }
-        }
+    fn test_part_two() {
+        // Part two through as many keypads as part one, with its answer for the example
+        let params = Day21Params {
+            depth_two: 2,
+            ..Default::default()
+        };
+        assert_eq!(Day21::solve_part_two_with(INPUT, &params).unwrap(), 126384);
     }
 
     #[test]
-    fn test_part_two() {
-        for i in 0..2 {
-            let res = solve_part_two(INPUT[i]);
-            match res {
-                AoCResult::Int(v) => assert_eq!(v, EXPECTED_PART_TWO[i]),
-                _ => panic!("Wrong result type returned"),
-            }
-        }
+    fn test_no_keypads() {
+        assert_eq!(expand_moves("<A^A>^^AvvvA", 0), 12);
+        let params = Day21Params {
+            depth_one: 0,
+            ..Default::default()
+        };
+        assert_eq!(Day21::solve_part_one_with(INPUT, &params).unwrap(), 25392);
     }
 }
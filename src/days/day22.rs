@@ -0,0 +1,169 @@
+#![allow(unused)]
+
+use crate::{par, params, parse, Answer, AoCResult, ParseError, Solver};
+
+/// Initial secret numbers of the buyers
+pub fn parse_input(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(parse::num)
+        .collect()
+}
+
+params! {
+    /// New secret numbers generated by each buyer
+    pub struct Day22Params {
+        steps: usize = 2000,
+    }
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Input<'a> = (Vec<u64>, Day22Params);
+    type Params = Day22Params;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Self::parse_with(input, &Day22Params::default())
+    }
+
+    fn parse_with<'a>(input: &'a str, params: &Day22Params) -> Result<Self::Input<'a>, ParseError> {
+        Ok((parse_input(input)?, *params))
+    }
+
+    fn part_one((secrets, params): &Self::Input<'_>) -> AoCResult {
+        let limit = params.steps;
+
+        let res = secrets
+            .iter()
+            .map(|&(mut secret)| {
+                for i in 0..limit {
+                    // n = ((n * 64) ^ n) % 16777216;
+                    // n = ((n / 32) ^ n) % 16777216;
+                    // n = ((n * 2048) ^ n) % 16777216;
+                    secret = ((secret << 6) ^ secret) & 0xffffff;
+                    secret = ((secret >> 5) ^ secret) & 0xffffff;
+                    secret = ((secret << 11) ^ secret) & 0xffffff;
+                }
+                secret
+            })
+            .sum::<u64>();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((secrets, params): &Self::Input<'_>) -> AoCResult {
+        // Gains of each sequence, for each chunk of monkeys
+        let gains = par::map_chunks(secrets, |secrets| {
+            let mut sequence_gains = vec![0u64; DIFF_SZ.pow(4)];
+            for &secret in secrets {
+                add_sequence_gains(secret, params.steps, &mut sequence_gains);
+            }
+            sequence_gains
+        });
+
+        let res = (0..DIFF_SZ.pow(4))
+            .map(|k| gains.iter().map(|g| g[k]).sum::<u64>())
+            .max()
+            .unwrap();
+        Ok(Answer::from(res))
+    }
+}
+
+// Number of different price differences
+const DIFF_SZ: usize = 19;
+
+// Adds the price a monkey sells at to the gain of each sequence of differences, over `steps`
+// secret numbers. There can be at most 19**4 different sequences
+fn add_sequence_gains(mut secret: u64, steps: usize, sequence_gains: &mut [u64]) {
+    let limit = steps.saturating_sub(1);
+
+    // Prices and differences in prices for the monkey
+    let mut prices = vec![0u8; limit + 1];
+    let mut diff = vec![0i8; limit];
+
+    prices[0] = (secret % 10) as u8;
+    // First price seen for each different sequence
+    let mut monkey_sequence_prices = vec![0u8; DIFF_SZ.pow(4)];
+    for i in 0..limit {
+        // Calculate price and diff to the previous
+        secret = ((secret << 6) ^ secret) & 0xffffff;
+        secret = ((secret >> 5) ^ secret) & 0xffffff;
+        secret = ((secret << 11) ^ secret) & 0xffffff;
+        let price = (secret % 10) as u8;
+        prices[i + 1] = price;
+        diff[i] = prices[i + 1] as i8 - prices[i] as i8;
+
+        if i >= 3 {
+            // Calculate diff sequence and if it's the first time we see it, store it
+            let k = (diff[i - 3] + 9) as usize * DIFF_SZ.pow(3)
+                + (diff[i - 2] + 9) as usize * DIFF_SZ.pow(2)
+                + (diff[i - 1] + 9) as usize * DIFF_SZ
+                + (diff[i] + 9) as usize;
+            if monkey_sequence_prices[k] == 0 {
+                monkey_sequence_prices[k] = price;
+
+                sequence_gains[k] += price as u64;
+            }
+        }
+    }
+}
+
+// pub fn solve_part_two(input: &str) -> AoCResult {
+//     let secrets = parse_input(input)?;
+//     let limit = 2000 - 1;
+//
+//     let mut prices = vec![vec![0u8; limit + 1]; secrets.len()];
+//     let mut diff = vec![vec![0i8; limit]; secrets.len()];
+//
+//     // let mut sequence_gains = HashMap::<(i8, i8, i8, i8), u64>::new();
+//     let mut sequence_gains = HashMap::<u64, u64>::new();
+//     for (monkey, &(mut secret)) in secrets.iter().enumerate() {
+//         prices[monkey][0] = (secret % 10) as u8;
+//         // let mut monkey_sequence_prices = HashMap::<(i8, i8, i8, i8), u8>::new();
+//         let mut monkey_sequence_prices = HashMap::<u64, u8>::new();
+//         for i in 0..limit {
+//             secret = ((secret << 6) ^ secret) & 0xffffff;
+//             secret = ((secret >> 5) ^ secret) & 0xffffff;
+//             secret = ((secret << 11) ^ secret) & 0xffffff;
+//             prices[monkey][i + 1] = (secret % 10) as u8;
+//             diff[monkey][i] = prices[monkey][i + 1] as i8 - prices[monkey][i] as i8;
+//         }
+//
+//         for i in 3..limit {
+//             // let k = (diff[monkey][i - 3], diff[monkey][i - 2], diff[monkey][i - 1], diff[monkey][i]);
+//             let k = ((diff[monkey][i - 3] as u8) as u64) << 24
+//                 | ((diff[monkey][i - 2] as u8) as u64) << 16
+//                 | ((diff[monkey][i - 1] as u8) as u64) << 8
+//                 | ((diff[monkey][i] as u8) as u64);
+//             let price = prices[monkey][i + 1];
+//             monkey_sequence_prices.entry(k).or_insert(price);
+//         }
+//         for (k, price) in monkey_sequence_prices {
+//             sequence_gains
+//                 .entry(k)
+//                 .and_modify(|v| *v += price as u64)
+//                 .or_insert(price as u64);
+//         }
+//     }
+//     let res = *sequence_gains.values().max().unwrap_or(&0);
+//     Ok(Answer::from(res))
+// }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        // The first 10 secret numbers of the example
+        let params = Day22Params { steps: 10 };
+        assert_eq!(Day22::solve_part_one_with("123", &params).unwrap(), 5908254);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input1 = "1\n2\n3\n2024";
+        assert_eq!(Day22::solve_part_two(input1).unwrap(), 23);
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Network, as the computers connected to each one
+pub fn parse_input(input: &str) -> Result<HashMap<&str, HashSet<&str>>, ParseError> {
+    let mut graph = HashMap::new();
+    for line in input.trim().lines() {
+        let (k, v) = parse::split_once(line, "-")?;
+        graph.entry(k).or_insert_with(HashSet::new).insert(v);
+        graph.entry(v).or_insert_with(HashSet::new).insert(k);
+    }
+    Ok(graph)
+}
+
+/// Largest clique extending `r` with computers from `p` and none from `x`, by the
+/// Bron-Kerbosch algorithm
+pub fn bron_kerbosch<'a>(
+    r: &HashSet<&'a str>,
+    p: &HashSet<&'a str>,
+    x: &HashSet<&str>,
+    graph: &HashMap<&'a str, HashSet<&'a str>>,
+) -> Rc<HashSet<&'a str>> {
+    if p.is_empty() && x.is_empty() {
+        Rc::new(r.clone())
+    } else if p.is_empty() {
+        Rc::new(HashSet::new())
+    } else {
+        let mut max_clique: Rc<HashSet<&str>> = Rc::new(HashSet::new());
+        let mut p_loop = p.clone();
+        let mut x_loop = x.clone();
+        let pivot = p.iter().next().copied().unwrap();
+        let pivot_neighbors = graph.get(pivot).unwrap();
+        for &v in p.difference(pivot_neighbors) {
+            let mut r_rec = r.clone();
+            r_rec.insert(v);
+            let p_rec = p_loop
+                .clone()
+                .intersection(graph.get(v).unwrap())
+                .copied()
+                .collect();
+            let x_rec = x_loop
+                .clone()
+                .intersection(graph.get(v).unwrap())
+                .copied()
+                .collect();
+            let clique = bron_kerbosch(&r_rec, &p_rec, &x_rec, graph);
+            if max_clique.len() < clique.len() {
+                max_clique = Rc::clone(&clique);
+            }
+            p_loop.remove(v);
+            x_loop.insert(v);
+        }
+
+        max_clique
+    }
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Input<'a> = HashMap<&'a str, HashSet<&'a str>>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(graph: &Self::Input<'_>) -> AoCResult {
+        let mut visited = HashSet::new();
+        let mut res = 0u32;
+        for (&a, a_edges) in graph {
+            let mut marks = a_edges.clone();
+            for &b in a_edges.difference(&visited) {
+                let b_edges = graph.get(b).unwrap();
+                for &c in marks.intersection(b_edges) {
+                    if !visited.contains(&c) && [a, b, c].iter().any(|node| node.starts_with("t")) {
+                        res += 1;
+                    }
+                }
+                marks.remove(b);
+            }
+            visited.insert(a);
+        }
+
+        Ok(Answer::from(res))
+    }
+
+    fn part_two(graph: &Self::Input<'_>) -> AoCResult {
+        let r = HashSet::new();
+        let p = graph.keys().copied().collect::<HashSet<_>>();
+        let x = HashSet::new();
+        let max_clique = bron_kerbosch(&r, &p, &x, graph);
+
+        let res = max_clique.iter().sorted().copied().join(",");
+        Ok(Answer::Str(res))
+    }
+}
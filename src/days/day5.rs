@@ -0,0 +1,77 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// Pages that must come after each page
+pub type Rules = HashMap<i32, Vec<i32>>;
+
+/// Page ordering rules, and the pages of each update
+pub fn parse_input(input: &str) -> Result<(Rules, Vec<Vec<i32>>), ParseError> {
+    let (rules_in, orders_in) = parse::split_once(input.trim(), "\n\n")?;
+
+    let mut rules = Rules::new();
+    for line in rules_in.lines() {
+        let (before, after) = parse::split_once(line, "|")?;
+        rules
+            .entry(parse::num(before)?)
+            .or_default()
+            .push(parse::num(after)?);
+    }
+
+    let orders = orders_in
+        .lines()
+        .map(|line| line.split(',').map(parse::num).try_collect())
+        .try_collect()?;
+
+    Ok((rules, orders))
+}
+
+/// Whether each page of an update is allowed before the next one by the rules
+pub fn is_valid_order(order: &[i32], rules: &Rules) -> bool {
+    order
+        .windows(2)
+        .all(|v| rules.contains_key(&v[0]) && rules.get(&v[0]).unwrap().contains(&v[1]))
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Input<'a> = (Rules, Vec<Vec<i32>>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((rules, orders): &Self::Input<'_>) -> AoCResult {
+        let res: i32 = orders
+            .iter()
+            .filter(|order| is_valid_order(order, rules))
+            .map(|order| order.get(order.len() / 2).copied().unwrap())
+            .sum();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((rules, orders): &Self::Input<'_>) -> AoCResult {
+        let mut orders = orders.clone();
+
+        let res: i32 = orders
+            .iter_mut()
+            .filter(|order| !is_valid_order(order, rules))
+            .map(|order| {
+                order.sort_by(|a, b| {
+                    if !rules.contains_key(a) {
+                        std::cmp::Ordering::Greater
+                    } else if rules.get(a).unwrap().contains(b) {
+                        std::cmp::Ordering::Less
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                });
+                order[order.len() / 2]
+            })
+            .sum();
+
+        Ok(Answer::from(res))
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{par, stats, visual, Answer, AoCResult, Dir, Grid, ParseError, Pos, Rotation, Solver};
+
+/// Map of the lab, with the guard facing up as `^`
+pub fn parse_input(input: &str) -> Result<Grid<char>, ParseError> {
+    let map = Grid::parse(input)?;
+    if map.find(&'^').is_none() {
+        let input = input.trim_end();
+        return Err(ParseError::new(&input[input.len()..], "the guard, '^'"));
+    }
+    Ok(map)
+}
+
+/// Starting position of the guard
+pub fn find_start(map: &Grid<char>) -> Pos {
+    map.find(&'^').expect("The guard is checked when parsing")
+}
+
+// Frame of the map with the visited positions marked, and the guard at `pos` facing `dir`
+fn walk_frame(
+    map: &Grid<char>,
+    visited: &[u8],
+    pos: Pos,
+    dir: Dir,
+    label: String,
+) -> visual::Frame {
+    let mut grid = map.clone();
+    for (idx, _) in visited.iter().enumerate().filter(|(_, &v)| v != 0) {
+        grid[map.pos(idx)] = 'X';
+    }
+    grid[pos] = match dir {
+        Dir::UP => '^',
+        Dir::RIGHT => '>',
+        Dir::DOWN => 'v',
+        _ => '<',
+    };
+    visual::Frame::new(label, grid)
+}
+
+/// Walks through the map with the given start, returns whether a loop was found and
+/// the visited positions as a 1D bitmask of directions. Each step is recorded as a frame if `record`
+pub fn walk_map(map: &Grid<char>, mut pos: Pos, record: bool) -> (bool, Vec<u8>) {
+    stats::count("walks");
+    // 1D bitmask of visited positions, which stores the directions it was visited
+    let mut visited = vec![0u8; map.width() * map.height()];
+    let mut dir = Dir::UP;
+    // Direction to use with bitmask
+    let mut dir_idx = 0;
+    let mut curr_dir = 1u8 << dir_idx;
+    loop {
+        // Check if already passed through here in the same direction
+        let visited_idx = map.idx(pos);
+        if (visited[visited_idx] & curr_dir) != 0 {
+            return (true, visited);
+        }
+        // Mark visited bitmask
+        visited[visited_idx] |= curr_dir;
+        if record {
+            visual::step(|| walk_frame(map, &visited, pos, dir, "Part one, walking".to_string()));
+        }
+
+        match pos.checked_add(dir, map.dims) {
+            // Left the board
+            None => {
+                if record {
+                    let label = "Part one, left the map".to_string();
+                    visual::keyframe(|| walk_frame(map, &visited, pos, dir, label));
+                }
+                break;
+            }
+            Some(next) if map[next] == '#' => {
+                // Turn
+                dir = dir.rotate(Rotation::Clockwise);
+                dir_idx = (dir_idx + 1) % 4;
+                curr_dir = 1 << dir_idx;
+            }
+            Some(next) => pos = next,
+        }
+    }
+
+    (false, visited)
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Input<'a> = Grid<char>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(map: &Self::Input<'_>) -> AoCResult {
+        let (_loops, visited) = walk_map(map, find_start(map), true);
+
+        let res = visited.iter().map(|&v| (v != 0) as i64).sum();
+        Ok(Answer::Int(res))
+    }
+
+    fn part_two(map: &Self::Input<'_>) -> AoCResult {
+        let start = find_start(map);
+        let (_, visited) = walk_map(map, start, false);
+        // Can't place a wall on the starting point
+        let candidates = map
+            .positions()
+            .filter(|&pos| pos != start && visited[map.idx(pos)] != 0)
+            .collect::<Vec<_>>();
+        let res = par::map_chunks(&candidates, |chunk| {
+            let mut map = map.clone();
+            let mut res = 0i64;
+            for &pos in chunk {
+                map[pos] = '#';
+                let (loops, _) = walk_map(&map, start, false);
+                if loops {
+                    res += 1
+                };
+                map[pos] = '.';
+            }
+            res
+        });
+        Ok(Answer::Int(res.iter().sum()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let input = "..#\n...\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 4));
+        assert_eq!(err.expected, "the guard, '^'");
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{par, parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+
+/// Equations, as the test value and its numbers
+pub fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    // Each line has the total and the vector of numbers to check
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (n, ns) = parse::split_once(line, ": ")?;
+            Ok((
+                parse::num(n)?,
+                ns.split_whitespace().map(parse::num).try_collect()?,
+            ))
+        })
+        .try_collect()
+}
+
+/// Whether the numbers can be combined to the total, with `num_ops` operations
+pub fn can_combine(total: i64, ns: &[i64], num_ops: i32) -> bool {
+    // Current and next stack of results
+    let mut curr_stack = vec![ns[0]];
+    let mut next_stack = Vec::new();
+    // Loop through each position, placing each of the operations
+    for pos in 0..ns.len() - 1 {
+        while let Some(curr) = curr_stack.pop() {
+            if curr > total {
+                continue;
+            }
+            next_stack.push(curr + ns[pos + 1]);
+            next_stack.push(curr * ns[pos + 1]);
+            if num_ops == 3 {
+                next_stack.push(curr * 10_i64.pow(1 + ns[pos + 1].ilog10()) + ns[pos + 1]);
+            }
+        }
+        std::mem::swap(&mut curr_stack, &mut next_stack);
+    }
+    curr_stack.contains(&total)
+}
+
+/// Sum of the test values of the equations whose numbers can be combined to them, with
+/// `num_ops` operations
+pub fn count_combinations(values: &[(i64, Vec<i64>)], num_ops: i32) -> i64 {
+    par::map(values, |(total, ns)| {
+        if can_combine(*total, ns, num_ops) {
+            *total
+        } else {
+            0
+        }
+    })
+    .iter()
+    .sum()
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Input<'a> = Vec<(i64, Vec<i64>)>;
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(values: &Self::Input<'_>) -> AoCResult {
+        let res = count_combinations(values, 2);
+        Ok(Answer::Int(res))
+    }
+
+    fn part_two(values: &Self::Input<'_>) -> AoCResult {
+        let res = count_combinations(values, 3);
+        Ok(Answer::Int(res))
+    }
+}
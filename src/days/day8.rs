@@ -0,0 +1,92 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::{math, Answer, AoCResult, Dir, Grid, IPos, ParseError, Pos, Solver};
+
+/// Locations of the antennas of each frequency
+pub type Locations = HashMap<char, Vec<Pos>>;
+
+/// Return the map as a grid of chars, and a hashmap with the locations for each antenna type.
+pub fn parse_input(input: &str) -> Result<(Grid<char>, Locations), ParseError> {
+    let map = Grid::parse(input.trim())?;
+
+    let mut locations = Locations::new();
+    for (pos, &c) in map.iter_pos() {
+        if c != '.' {
+            locations.entry(c).or_default().push(pos);
+        }
+    }
+
+    Ok((map, locations))
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Input<'a> = (Grid<char>, Locations);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((map, locations): &Self::Input<'_>) -> AoCResult {
+        let mut antinodes = HashSet::new();
+        for (_, loc) in locations.iter() {
+            for pair in loc.iter().combinations(2) {
+                let start = IPos::from(*pair[0]);
+                let step = IPos::from(*pair[1]) - start;
+
+                for antinode in [start - step, start + step * 2] {
+                    if antinode.inside(map.dims) {
+                        antinodes.insert(antinode);
+                    }
+                }
+            }
+        }
+        let res = antinodes.len();
+        Ok(Answer::from(res))
+    }
+
+    fn part_two((map, locations): &Self::Input<'_>) -> AoCResult {
+        let mut antinodes = HashSet::new();
+        for (_, loc) in locations.iter() {
+            for pair in loc.iter().combinations(2) {
+                let start = IPos::from(*pair[0]);
+                let step = IPos::from(*pair[1]) - start;
+                // Smallest step between positions on the line, so that none is skipped
+                let g = math::gcd(step.x as i64, step.y as i64) as isize;
+                let step = Dir {
+                    x: step.x / g,
+                    y: step.y / g,
+                };
+
+                let mut antinode = start - step;
+                while antinode.inside(map.dims) {
+                    antinodes.insert(antinode);
+                    antinode -= step;
+                }
+
+                let mut antinode = start;
+                while antinode.inside(map.dims) {
+                    antinodes.insert(antinode);
+                    antinode += step;
+                }
+            }
+        }
+        let res = antinodes.len();
+        Ok(Answer::from(res))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_two() {
+        // Antennas 2 apart have antinodes between them too
+        let input = "a....\n.....\n..a..\n.....\n.....\n";
+        assert_eq!(Day8::solve_part_two(input).unwrap(), 5);
+    }
+}
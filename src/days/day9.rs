@@ -0,0 +1,131 @@
+use crate::{parse, Answer, AoCResult, ParseError, Solver};
+use itertools::Itertools;
+
+/// Returns the input values and a memory representation
+pub fn parse_input(input: &str) -> Result<(Vec<u32>, Vec<i32>), ParseError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseError::new(input, "a disk map"));
+    }
+    let vals: Vec<u32> = input
+        .char_indices()
+        .map(|(i, _)| parse::digit(input, i))
+        .try_collect()?;
+
+    let sz = vals.iter().sum::<u32>() as usize;
+    let mut mem = vec![-1; sz];
+    let mut mem_ptr = 0usize;
+    for (i, &v) in vals.iter().enumerate() {
+        let mem_contents = if (i % 2) == 0 { i as i32 / 2 } else { -1 };
+        for j in 0..v {
+            mem[mem_ptr + j as usize] = mem_contents;
+        }
+        mem_ptr += v as usize;
+    }
+
+    Ok((vals, mem))
+}
+
+/// Checksum of a memory representation, where free blocks are -1
+pub fn checksum(mem: &[i32]) -> u64 {
+    mem.iter()
+        .enumerate()
+        .map(|(i, &v)| if v != -1 { i as u64 * v as u64 } else { 0 })
+        .sum()
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Input<'a> = (Vec<u32>, Vec<i32>);
+    type Params = ();
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one((_vals, mem): &Self::Input<'_>) -> AoCResult {
+        let mut mem = mem.clone();
+
+        // Move the last block to the first free one, until no free block is before a file
+        let mut start = 0usize;
+        let mut end = mem.len();
+        loop {
+            while start < end && mem[start] != -1 {
+                start += 1;
+            }
+            while start < end && mem[end - 1] == -1 {
+                end -= 1;
+            }
+            if start >= end {
+                break;
+            }
+            mem.swap(start, end - 1);
+        }
+
+        Ok(Answer::UInt(checksum(&mem)))
+    }
+
+    fn part_two((vals, mem): &Self::Input<'_>) -> AoCResult {
+        let mut mem = mem.clone();
+        let mut moved = vals.clone();
+        let initial_offsets = moved
+            .iter()
+            .scan(0, |state, &x| {
+                let res = *state;
+                *state += x;
+                Some(res)
+            })
+            .collect::<Vec<_>>();
+
+        // Move each value starting from the end
+        for from in (0..vals.len()).step_by(2).rev() {
+            let need = vals[from];
+            let offset_from = initial_offsets[from];
+            let mut offset_to = 0;
+            // Find space for it starting from the start
+            for to in (1..from).step_by(2) {
+                offset_to += moved[to - 1];
+                if moved[to] >= need {
+                    // Found space, move. Update from, to and free space around each one
+                    moved[from] -= need;
+                    moved[from - 1] += need;
+                    moved[to] -= need;
+                    moved[to - 1] += need;
+
+                    // Relocate mem
+                    for idx in 0..need {
+                        mem[(offset_to + idx) as usize] = mem[(offset_from + idx) as usize];
+                        mem[(offset_from + idx) as usize] = -1;
+                    }
+                    break;
+                }
+                offset_to += moved[to];
+            }
+        }
+
+        Ok(Answer::UInt(checksum(&mem)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(parse_input("\n").unwrap_err().expected, "a disk map");
+        let input = "12a45\n";
+        let err = parse_input(input).unwrap_err().locate(input);
+        assert_eq!((err.column, err.found.as_str()), (3, "a"));
+    }
+
+    #[test]
+    fn test_no_free_space() {
+        for (input, expected) in [("1", 0), ("8", 0), ("0", 0), ("108", 36)] {
+            let input = parse_input(input).unwrap();
+            assert_eq!(Day9::part_one(&input).unwrap(), Answer::UInt(expected));
+            assert_eq!(Day9::part_two(&input).unwrap(), Answer::UInt(expected));
+        }
+    }
+}
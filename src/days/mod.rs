@@ -0,0 +1,59 @@
+//! Solutions of the days of 2024. Each day has its `Solver`, like `day17::Day17`, along with
+//! the parser of its input and the algorithms it is built on, which can be used on their own.
+
+use crate::Entry;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day2;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// All the available days, in order
+pub const DAYS: &[Entry] = &[
+    Entry::new::<day1::Day1>(1),
+    Entry::new::<day2::Day2>(2),
+    Entry::new::<day3::Day3>(3),
+    Entry::new::<day4::Day4>(4),
+    Entry::new::<day5::Day5>(5),
+    Entry::new::<day6::Day6>(6),
+    Entry::new::<day7::Day7>(7),
+    Entry::new::<day8::Day8>(8),
+    Entry::new::<day9::Day9>(9),
+    Entry::new::<day10::Day10>(10),
+    Entry::new::<day11::Day11>(11),
+    Entry::new::<day12::Day12>(12),
+    Entry::new::<day13::Day13>(13),
+    Entry::new::<day14::Day14>(14),
+    Entry::new::<day15::Day15>(15),
+    Entry::new::<day16::Day16>(16),
+    Entry::new::<day17::Day17>(17),
+    Entry::new::<day18::Day18>(18),
+    Entry::new::<day19::Day19>(19),
+    Entry::new::<day20::Day20>(20),
+    Entry::new::<day21::Day21>(21),
+    Entry::new::<day22::Day22>(22),
+    Entry::new::<day23::Day23>(23),
+    Entry::new::<day24::Day24>(24),
+    Entry::new::<day25::Day25>(25),
+];
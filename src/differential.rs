@@ -0,0 +1,221 @@
+//! Differential tests of the optimized solvers against naive reference ones, on generated
+//! inputs. Mismatches are shrunk to the smallest input found that still shows them, first by
+//! generating smaller inputs and then by dropping lines.
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::days::{day17, day19, day20, DAYS};
+use crate::params::{Overrides, Params};
+use crate::{generate, AoCResult, ParseError, Parts};
+
+/// Reference solver of a day, with how to check it
+pub struct Reference {
+    pub day: u16,
+    /// Size of the generated inputs checked
+    pub size: usize,
+    /// Parameters of the day, suited to the smaller generated inputs
+    pub params: &'static [(&'static str, &'static str)],
+    /// Whether the lines of the inputs, past the first blank one if any, are independent of
+    /// each other, like the designs of day 19, so that they can be dropped when shrinking
+    pub independent_lines: bool,
+    solve: fn(&str, &Overrides) -> Result<[AoCResult; 2], ParseError>,
+}
+
+/// Days with a reference solver, in order
+pub const REFERENCES: &[Reference] = &[
+    Reference {
+        day: 17,
+        size: 9,
+        params: &[],
+        independent_lines: false,
+        solve: |input, _| day17::reference::solve(input),
+    },
+    Reference {
+        day: 19,
+        size: 20,
+        params: &[],
+        independent_lines: true,
+        solve: |input, _| day19::reference::solve(input),
+    },
+    Reference {
+        day: 20,
+        size: 21,
+        params: &[("min_save", "4")],
+        independent_lines: false,
+        solve: |input, params| day20::reference::solve(input, &day20::Day20Params::with(params)?),
+    },
+];
+
+/// Reference solver of `day`, if it has one
+pub fn reference(day: u16) -> Option<&'static Reference> {
+    REFERENCES.iter().find(|reference| reference.day == day)
+}
+
+// Answers of both parts, None for errors
+type Answers = [Option<String>; 2];
+
+fn answers(parts: Result<[AoCResult; 2], ParseError>) -> Answers {
+    match parts {
+        Ok(parts) => parts.map(|part| part.ok().map(|answer| answer.to_string())),
+        Err(_) => [None, None],
+    }
+}
+
+/// Input on which a solver and its reference disagree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub day: u16,
+    pub part: u8,
+    /// Answer of the reference, None if it failed
+    pub expected: Option<String>,
+    /// Answer of the solver, None if it failed
+    pub found: Option<String>,
+    /// Seed and size of the generated input the mismatch was first found on
+    pub seed: u64,
+    pub size: usize,
+    /// Smallest input found with the mismatch
+    pub input: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let answer = |answer: &Option<String>| answer.clone().unwrap_or("no answer".to_string());
+        write!(
+            f,
+            "Day {}, part {}: {} instead of {}, on the input shrunk from seed {} and size {}:\n{}",
+            self.day,
+            self.part,
+            answer(&self.found),
+            answer(&self.expected),
+            self.seed,
+            self.size,
+            self.input
+        )
+    }
+}
+
+impl Reference {
+    // Part whose answers differ on `input`, with those of the reference and of the solver
+    fn mismatch(&self, input: &str) -> Option<(u8, Option<String>, Option<String>)> {
+        let params = self
+            .params
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        let entry = DAYS.iter().find(|entry| entry.day == self.day)?;
+        let found = answers(entry.run_with(input, Parts::Both, &params).map(|run| {
+            [run.part_one, run.part_two].map(|part| part.expect("Both parts are run").answer)
+        }));
+        let expected = answers((self.solve)(input, &params));
+        (0..2)
+            .find(|&i| expected[i] != found[i])
+            .map(|i| (i as u8 + 1, expected[i].clone(), found[i].clone()))
+    }
+
+    // Smallest input with a mismatch: generated with the smallest size from any of `seeds`,
+    // and then without the lines that can be dropped
+    fn shrink(&self, seeds: Range<u64>, input: String) -> String {
+        let generator =
+            generate::generator(self.day).expect("Days with a reference have a generator");
+        let mut input = (1..self.size)
+            .flat_map(|size| seeds.clone().map(move |seed| (seed, size)))
+            .map(|(seed, size)| generator.generate(seed, size))
+            .find(|input| self.mismatch(input).is_some())
+            .unwrap_or(input);
+        if !self.independent_lines {
+            return input;
+        }
+
+        let mut lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+        let first = lines
+            .iter()
+            .position(|line| line.is_empty())
+            .map_or(0, |i| i + 1);
+        let mut i = first;
+        while i < lines.len() {
+            let line = lines.remove(i);
+            let candidate = lines.join("\n") + "\n";
+            if self.mismatch(&candidate).is_some() {
+                input = candidate;
+            } else {
+                lines.insert(i, line);
+                i += 1;
+            }
+        }
+        input
+    }
+}
+
+/// Checks the solver of the reference's day against it, on the inputs generated from each of
+/// `seeds`. Returns the first mismatch found, shrunk
+pub fn check(reference: &Reference, seeds: Range<u64>) -> Result<(), Mismatch> {
+    let generator =
+        generate::generator(reference.day).expect("Days with a reference have a generator");
+    for seed in seeds.clone() {
+        let input = generator.generate(seed, reference.size);
+        if reference.mismatch(&input).is_none() {
+            continue;
+        }
+        let input = reference.shrink(seeds, input);
+        let (part, expected, found) = reference.mismatch(&input).expect("Shrunk inputs mismatch");
+        return Err(Mismatch {
+            day: reference.day,
+            part,
+            expected,
+            found,
+            seed,
+            size: reference.size,
+            input,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+
+    #[test]
+    fn test_references() {
+        for reference in REFERENCES {
+            if let Err(mismatch) = check(reference, 0..20) {
+                panic!("{mismatch}");
+            }
+        }
+        assert!(reference(19).is_some() && reference(1).is_none());
+    }
+
+    #[test]
+    fn test_shrink() {
+        // Wrong on designs ending with a red stripe, which can't be made
+        let reference = Reference {
+            day: 19,
+            size: 20,
+            params: &[],
+            independent_lines: true,
+            solve: |input, _| {
+                let mut parts = day19::reference::solve(input)?;
+                if input.lines().skip(2).any(|design| design.ends_with('r')) {
+                    parts[0] = Ok(Answer::from(-1));
+                }
+                Ok(parts)
+            },
+        };
+        let mismatch = check(&reference, 0..5).unwrap_err();
+        assert_eq!((mismatch.day, mismatch.part), (19, 1));
+        assert_eq!(mismatch.expected.as_deref(), Some("-1"));
+        // A single design is left, with the patterns of the smallest input
+        let designs = mismatch.input.lines().skip(2).collect::<Vec<_>>();
+        assert_eq!(designs.len(), 1);
+        assert!(designs[0].ends_with('r'));
+        assert_eq!(
+            mismatch.input.lines().next().unwrap().split(", ").count(),
+            1
+        );
+        assert!(mismatch
+            .to_string()
+            .starts_with("Day 19, part 1: 0 instead of -1"));
+    }
+}
@@ -0,0 +1,627 @@
+//! Random inputs of the days, for stress and property tests. Each generator builds a valid
+//! input from a seed, so that the same seed and size always give the same input, with a size
+//! whose meaning depends on the day, like the side of a map or the number of lines.
+
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::days::day17::Day17;
+use crate::{search, Dir, Grid, Pos, Solver};
+
+/// Small pseudorandom number generator, SplitMix64, enough for generating inputs
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number on `range`, which can't be empty
+    pub fn range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        match (end - start).checked_add(1) {
+            Some(len) => start + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+
+    /// Number below `n`, which must be positive
+    pub fn below(&mut self, n: usize) -> usize {
+        self.range(0..=n as u64 - 1) as usize
+    }
+
+    /// True `percent` out of 100 times
+    pub fn percent(&mut self, percent: u64) -> bool {
+        self.below(100) < percent as usize
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// Generator of the inputs of a day
+pub struct Generator {
+    pub day: u16,
+    /// What the size of an input is, like the side of the map
+    pub size_desc: &'static str,
+    /// Size of the real inputs
+    pub default_size: usize,
+    make: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    /// Input of the given size, from `seed`
+    pub fn generate(&self, seed: u64, size: usize) -> String {
+        (self.make)(&mut Rng::new(seed), size)
+    }
+}
+
+/// Days with a generator, in order
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size_desc: "lines",
+        default_size: 1000,
+        make: day1,
+    },
+    Generator {
+        day: 2,
+        size_desc: "reports",
+        default_size: 1000,
+        make: day2,
+    },
+    Generator {
+        day: 6,
+        size_desc: "side of the map",
+        default_size: 130,
+        make: day6,
+    },
+    Generator {
+        day: 7,
+        size_desc: "equations",
+        default_size: 850,
+        make: day7,
+    },
+    Generator {
+        day: 9,
+        size_desc: "digits of the disk map",
+        default_size: 19999,
+        make: day9,
+    },
+    Generator {
+        day: 11,
+        size_desc: "stones",
+        default_size: 8,
+        make: day11,
+    },
+    Generator {
+        day: 16,
+        size_desc: "side of the maze",
+        default_size: 141,
+        make: day16,
+    },
+    Generator {
+        day: 17,
+        size_desc: "octal digits of register A, up to 21",
+        default_size: 9,
+        make: day17,
+    },
+    Generator {
+        day: 19,
+        size_desc: "designs",
+        default_size: 400,
+        make: day19,
+    },
+    Generator {
+        day: 20,
+        size_desc: "side of the racetrack",
+        default_size: 141,
+        make: day20,
+    },
+    Generator {
+        day: 22,
+        size_desc: "buyers",
+        default_size: 2000,
+        make: day22,
+    },
+    Generator {
+        day: 24,
+        size_desc: "bits of the adder, at least 6",
+        default_size: 45,
+        make: day24,
+    },
+    Generator {
+        day: 25,
+        size_desc: "locks and keys",
+        default_size: 500,
+        make: day25,
+    },
+];
+
+/// Generator of `day`, if it has one
+pub fn generator(day: u16) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+// Lines of text, each ending with a newline
+fn lines(items: impl IntoIterator<Item = String>) -> String {
+    items.into_iter().map(|line| line + "\n").collect()
+}
+
+// Two lists of location ids
+fn day1(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        format!(
+            "{}   {}",
+            rng.range(10000..=99999),
+            rng.range(10000..=99999)
+        )
+    }))
+}
+
+// Reports of levels, half of them safe and the rest with a level off
+fn day2(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let len = rng.range(5..=8) as usize;
+        let increasing = rng.percent(50);
+        let mut level = rng.range(25..=75) as i64;
+        let mut levels = Vec::with_capacity(len);
+        for _ in 0..len {
+            levels.push(level);
+            let step = rng.range(1..=3) as i64;
+            level += if increasing { step } else { -step };
+        }
+        if rng.percent(50) {
+            let i = rng.below(len);
+            levels[i] += rng.range(1..=5) as i64 * if rng.percent(50) { 1 } else { -1 };
+        }
+        levels
+            .iter()
+            .map(|level| level.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
+
+// Square map of obstructions, with the guard on an empty position
+fn day6(rng: &mut Rng, size: usize) -> String {
+    let side = size.max(2);
+    let mut map = Grid::new(Pos { x: side, y: side }, '.');
+    for pos in map.positions().collect::<Vec<_>>() {
+        if rng.percent(6) {
+            map[pos] = '#';
+        }
+    }
+    let empty = map.find_all(&'.').collect::<Vec<_>>();
+    let start = if empty.is_empty() {
+        Pos { x: 0, y: 0 }
+    } else {
+        *rng.choose(&empty)
+    };
+    map[start] = '^';
+    format!("{map}\n")
+}
+
+// Equations of 2 to 8 numbers, each made with the three operators
+fn day7(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let ns = (0..rng.range(2..=8))
+            .map(|_| rng.range(1..=99))
+            .collect::<Vec<_>>();
+        let total = ns[1..].iter().fold(ns[0], |acc, &n| match rng.below(3) {
+            0 => acc + n,
+            1 => acc * n,
+            _ => acc * 10u64.pow(n.ilog10() + 1) + n,
+        });
+        let ns = ns.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        format!("{total}: {}", ns.join(" "))
+    }))
+}
+
+// Disk map alternating files of 1 to 9 blocks and free spaces of up to 9, ending on a file
+fn day9(rng: &mut Rng, size: usize) -> String {
+    let map = (0..size.max(1) | 1)
+        .map(|i| {
+            let len = if i % 2 == 0 {
+                rng.range(1..=9)
+            } else {
+                rng.range(0..=9)
+            };
+            char::from(b'0' + len as u8)
+        })
+        .collect::<String>();
+    map + "\n"
+}
+
+// Stones with numbers of 1 to 7 digits
+fn day11(rng: &mut Rng, size: usize) -> String {
+    let stones = (0..size.max(1))
+        .map(|_| {
+            let digits = rng.range(1..=7) as u32;
+            rng.range(0..=10u64.pow(digits) - 1).to_string()
+        })
+        .collect::<Vec<_>>();
+    stones.join(" ") + "\n"
+}
+
+// Perfect maze with its cells on odd coordinates, surrounded by walls, with an odd side of at
+// least 5
+fn maze(rng: &mut Rng, size: usize) -> Grid<char> {
+    let side = size.max(5) / 2 * 2 + 1;
+    let mut grid = Grid::new(Pos { x: side, y: side }, '#');
+    let start = Pos { x: 1, y: side - 2 };
+    grid[start] = '.';
+    let mut stack = vec![start];
+    while let Some(&pos) = stack.last() {
+        let next = Dir::CARDINAL
+            .iter()
+            .filter_map(|&dir| {
+                let next = pos.checked_add(dir * 2, grid.dims)?;
+                (grid[next] == '#' && next.x > 0 && next.y > 0).then_some((dir, next))
+            })
+            .collect::<Vec<_>>();
+        if next.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let &(dir, next) = rng.choose(&next);
+        grid[pos + dir] = '.';
+        grid[next] = '.';
+        stack.push(next);
+    }
+    grid
+}
+
+// Maze with some loops, from S on the bottom left corner to E on the top right one
+fn day16(rng: &mut Rng, size: usize) -> String {
+    let mut grid = maze(rng, size);
+    let side = grid.width();
+    // Walls between two cells
+    for pos in grid.positions().collect::<Vec<_>>() {
+        let inside = pos.x > 0 && pos.y > 0 && pos.x < side - 1 && pos.y < side - 1;
+        if inside && (pos.x + pos.y) % 2 == 1 && rng.percent(10) {
+            grid[pos] = '.';
+        }
+    }
+    grid[Pos { x: 1, y: side - 2 }] = 'S';
+    grid[Pos { x: side - 2, y: 1 }] = 'E';
+    format!("{grid}\n")
+}
+
+// Programs of the same shape as the real inputs, which output a function of A and shift it
+// right by 3 bits until it is 0, with the shift anywhere after A is last read. Like those of
+// the real inputs, some value of A makes them output themselves
+fn day17(rng: &mut Rng, size: usize) -> String {
+    let digits = size.clamp(1, 21) as u32;
+    let a = rng.range(8u64.pow(digits - 1)..=8u64.pow(digits) - 1);
+    loop {
+        let mut body = vec![[1, rng.below(8)], [4, rng.below(8)]];
+        rng.shuffle(&mut body);
+        body.push([5, 5]);
+        body.insert(rng.below(body.len() + 1), [0, 3]);
+        let program = [[2, 4], [1, rng.below(8)], [7, 5]]
+            .into_iter()
+            .chain(body)
+            .chain([[3, 0]])
+            .flatten()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        let input = format!(
+            "Register A: {a}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            program.join(",")
+        );
+        if Day17::solve_part_two(&input).is_ok() {
+            return input;
+        }
+    }
+}
+
+// Towel patterns of 1 to 8 stripes, none ending with a red one, and designs made of them, half
+// of them followed by a red stripe so that they can't be made
+fn day19(rng: &mut Rng, size: usize) -> String {
+    const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+    let mut patterns = HashSet::new();
+    while patterns.len() < size.max(1) {
+        let len = rng.range(1..=8) as usize;
+        let pattern = (0..len).map(|_| *rng.choose(&COLORS)).collect::<String>();
+        if !pattern.ends_with('r') {
+            patterns.insert(pattern);
+        }
+    }
+    let mut patterns = patterns.into_iter().collect::<Vec<_>>();
+    patterns.sort();
+    rng.shuffle(&mut patterns);
+
+    let designs = (0..size.max(1)).map(|_| {
+        let len = rng.range(20..=60) as usize;
+        let mut design = String::new();
+        while design.len() < len {
+            design += rng.choose(&patterns).as_str();
+        }
+        if rng.percent(50) {
+            design.push('r');
+        }
+        design
+    });
+    format!("{}\n\n{}", patterns.join(", "), lines(designs))
+}
+
+// Racetrack of a single path through a maze, from S on the bottom left corner to E on the top
+// right one
+fn day20(rng: &mut Rng, size: usize) -> String {
+    let maze = maze(rng, size);
+    let (start, end) = (
+        Pos {
+            x: 1,
+            y: maze.height() - 2,
+        },
+        Pos {
+            x: maze.width() - 2,
+            y: 1,
+        },
+    );
+    let res = search::bfs(
+        start,
+        |&p| maze.neighbors(p).filter(|&n| maze[n] != '#'),
+        |&p| p == end,
+    );
+    let path = res.path(end).expect("Mazes are connected");
+    let mut grid = Grid::new(maze.dims, '#');
+    for pos in path {
+        grid[pos] = '.';
+    }
+    grid[start] = 'S';
+    grid[end] = 'E';
+    format!("{grid}\n")
+}
+
+// Initial secret numbers
+fn day22(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| rng.range(1..=(1 << 24) - 1).to_string()))
+}
+
+// Ripple carry adder of `size` bits, with four pairs of gate outputs swapped
+fn day24(rng: &mut Rng, size: usize) -> String {
+    adder(rng, size.clamp(6, 63), 4)
+}
+
+// Ripple carry adder of `bits` bits with the given swaps of outputs, each on a different bit
+// and between gates that don't depend on each other, so that the circuit has no loops
+fn adder(rng: &mut Rng, bits: usize, swaps: usize) -> String {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut Rng| loop {
+        let name = (0..3)
+            .map(|_| char::from(b'a' + rng.below(26) as u8))
+            .collect::<String>();
+        if !name.starts_with(['x', 'y', 'z']) && names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    // Gates of each bit: x ^ y, x & y, the sum, the carry through and the carry out
+    let mut gates = Vec::new();
+    let mut carry = name(rng);
+    gates.push(["x00".to_string(), "XOR".into(), "y00".into(), "z00".into()]);
+    gates.push(["x00".to_string(), "AND".into(), "y00".into(), carry.clone()]);
+    for i in 1..bits {
+        let (x, y, z) = (format!("x{i:02}"), format!("y{i:02}"), format!("z{i:02}"));
+        let (half, both, through) = (name(rng), name(rng), name(rng));
+        let carry_out = if i == bits - 1 {
+            format!("z{bits:02}")
+        } else {
+            name(rng)
+        };
+        gates.push([x.clone(), "XOR".into(), y.clone(), half.clone()]);
+        gates.push([x, "AND".into(), y, both.clone()]);
+        gates.push([half.clone(), "XOR".into(), carry.clone(), z]);
+        gates.push([half, "AND".into(), carry, through.clone()]);
+        gates.push([both, "OR".into(), through, carry_out.clone()]);
+        carry = carry_out;
+    }
+
+    // Pairs of gates of a bit, as their offsets from its first gate
+    const SWAPS: [(usize, usize); 4] = [(0, 1), (2, 3), (2, 1), (2, 4)];
+    let mut swapped_bits = (1..bits - 1).collect::<Vec<_>>();
+    rng.shuffle(&mut swapped_bits);
+    for &bit in swapped_bits.iter().take(swaps) {
+        let first = 2 + (bit - 1) * 5;
+        let (a, b) = *rng.choose(&SWAPS);
+        let out = gates[first + a][3].clone();
+        gates[first + a][3] = std::mem::replace(&mut gates[first + b][3], out);
+    }
+
+    rng.shuffle(&mut gates);
+    let wires = ['x', 'y']
+        .into_iter()
+        .flat_map(|c| (0..bits).map(move |i| (c, i)));
+    let wires = wires
+        .map(|(c, i)| format!("{c}{i:02}: {}", rng.below(2)))
+        .collect::<Vec<_>>();
+    let gates = gates.into_iter().map(|[in1, gate, in2, out]| {
+        let (in1, in2) = if rng.percent(50) {
+            (in1, in2)
+        } else {
+            (in2, in1)
+        };
+        format!("{in1} {gate} {in2} -> {out}")
+    });
+    format!("{}\n{}", lines(wires), lines(gates))
+}
+
+// Schematics of locks and keys, with pins of 0 to 5
+fn day25(rng: &mut Rng, size: usize) -> String {
+    let schematics = (0..size.max(1))
+        .map(|_| {
+            let lock = rng.percent(50);
+            let heights = (0..5).map(|_| rng.below(6)).collect::<Vec<_>>();
+            let rows = (0..7).map(|y| {
+                heights
+                    .iter()
+                    .map(|&h| {
+                        let filled = if lock { y <= h } else { y >= 6 - h };
+                        if filled {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            });
+            lines(rows)
+        })
+        .collect::<Vec<_>>();
+    schematics.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::DAYS;
+    use crate::{Answer, Parts};
+
+    // Answers of both parts of `day`, which must be solved
+    fn answers(day: u16, input: &str, params: &[(&str, &str)]) -> [Answer; 2] {
+        let params = params
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        let entry = DAYS.iter().find(|entry| entry.day == day).unwrap();
+        let run = entry.run_with(input, Parts::Both, &params).unwrap();
+        [run.part_one, run.part_two].map(|part| part.unwrap().answer.unwrap())
+    }
+
+    fn int(answer: &Answer) -> i64 {
+        answer.to_string().parse().unwrap()
+    }
+
+    #[test]
+    fn test_rng() {
+        // The first output of the reference SplitMix64
+        assert_eq!(Rng::new(0).next_u64(), 0xe220a8397b1dcdaf);
+        let mut rng = Rng::new(7);
+        assert!((0..100).all(|_| (3..=5).contains(&rng.range(3..=5))));
+        assert_eq!(rng.range(4..=4), 4);
+        rng.range(0..=u64::MAX);
+        let mut items = (0..10).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..10).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_generators() {
+        assert!(GENERATORS.windows(2).all(|w| w[0].day < w[1].day));
+        for generator in GENERATORS {
+            let size = (generator.default_size / 10).max(1);
+            for seed in 0..3 {
+                let input = generator.generate(seed, size);
+                assert_eq!(input, generator.generate(seed, size));
+                assert_eq!(crate::normalize_input(&input), input);
+                // Valid inputs of the day, with both parts solved
+                answers(generator.day, &input, &[]);
+            }
+            assert_ne!(generator.generate(0, size), generator.generate(1, size));
+        }
+    }
+
+    #[test]
+    fn test_small_disk_maps() {
+        // Down to a single file, without any free space
+        for size in 1..=20 {
+            for seed in 0..5 {
+                let input = day9(&mut Rng::new(seed), size);
+                answers(9, &input, &[]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_properties() {
+        for seed in 0..5 {
+            let mut rng = Rng::new(seed);
+
+            // The distance between the lists doesn't depend on their order
+            let input = day1(&mut rng, 50);
+            let swapped = input
+                .lines()
+                .map(|line| {
+                    line.split_whitespace()
+                        .rev()
+                        .collect::<Vec<_>>()
+                        .join("   ")
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                answers(1, &input, &[])[0],
+                answers(1, &lines(swapped), &[])[0]
+            );
+
+            // Every equation can be made with the three operators
+            let input = day7(&mut rng, 50);
+            let total = input
+                .lines()
+                .map(|line| line.split(':').next().unwrap().parse::<i64>().unwrap())
+                .sum::<i64>();
+            let [one, two] = answers(7, &input, &[]);
+            assert!(int(&one) <= total);
+            assert_eq!(int(&two), total);
+
+            // Turning costs more than the distance to E
+            let input = day16(&mut rng, 21);
+            let [one, two] = answers(16, &input, &[]);
+            assert!(int(&one) >= 2 * 18 + 1000);
+            assert!(int(&two) > 2 * 18);
+
+            // Only the designs ending with a red stripe can't be made
+            let input = day19(&mut rng, 30);
+            let possible = input.lines().skip(2).filter(|d| !d.ends_with('r')).count();
+            let [one, two] = answers(19, &input, &[]);
+            assert_eq!(int(&one), possible as i64);
+            assert!(int(&two) >= int(&one));
+
+            // Longer cheats include the shorter ones
+            let input = day20(&mut rng, 21);
+            let [one, two] = answers(20, &input, &[("min_save", "2")]);
+            assert!(int(&one) <= int(&two));
+
+            // Without swaps, the adder adds
+            let input = adder(&mut rng, 10, 0);
+            let operand = |c| {
+                (0..10).fold(0, |acc, i| {
+                    let bit = input.contains(&format!("{c}{i:02}: 1"));
+                    acc | (bit as i64) << i
+                })
+            };
+            let [one, _] = answers(24, &input, &[]);
+            assert_eq!(int(&one), operand('x') + operand('y'));
+            assert_ne!(day24(&mut rng, 10), input);
+
+            // Each lock fits at most every key
+            let input = day25(&mut rng, 20);
+            let locks = input
+                .split("\n\n")
+                .filter(|schematic| schematic.starts_with("#####"))
+                .count();
+            let [one, _] = answers(25, &input, &[]);
+            assert!(int(&one) <= (locks * (20 - locks)) as i64);
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Dir, ParseError, Pos};
+
+/// A 2D grid, stored as a flat vector in row-major order and indexed by `Pos`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub dims: Pos,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its row-major contents, panics if the sizes don't match
+    pub fn from_vec(dims: Pos, data: Vec<T>) -> Grid<T> {
+        assert_eq!(dims.x * dims.y, data.len(), "Grid data doesn't match dims");
+        Grid { dims, data }
+    }
+
+    /// Builds a grid from the lines of the input, converting each char with `f`, which
+    /// returns `None` for chars that aren't `expected`.
+    /// Empty lines are ignored and all lines must have the same length
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut data = Vec::new();
+        let mut dims = Pos { x: 0, y: 0 };
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            for (i, c) in line.char_indices() {
+                let v =
+                    f(c).ok_or_else(|| ParseError::new(&line[i..i + c.len_utf8()], expected))?;
+                data.push(v);
+            }
+            if dims.y == 0 {
+                dims.x = data.len();
+            }
+            dims.y += 1;
+            if data.len() != dims.x * dims.y {
+                let expected = format!("a row with {} cells", dims.x);
+                return Err(ParseError::new(line, expected));
+            }
+        }
+        Ok(Grid { dims, data })
+    }
+
+    pub fn width(&self) -> usize {
+        self.dims.x
+    }
+
+    pub fn height(&self) -> usize {
+        self.dims.y
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.dims.x && pos.y < self.dims.y
+    }
+
+    /// Index on the flat storage of the given position
+    pub fn idx(&self, pos: Pos) -> usize {
+        pos.y * self.dims.x + pos.x
+    }
+
+    /// Position of the given index on the flat storage
+    pub fn pos(&self, idx: usize) -> Pos {
+        Pos {
+            x: idx % self.dims.x,
+            y: idx / self.dims.x,
+        }
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.data[self.idx(pos)])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            let idx = self.idx(pos);
+            Some(&mut self.data[idx])
+        } else {
+            None
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Iterates over all positions, in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.dims.x;
+        (0..self.data.len()).map(move |idx| Pos {
+            x: idx % width,
+            y: idx / width,
+        })
+    }
+
+    /// Iterates over all positions and corresponding values, in row-major order
+    pub fn iter_pos(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.data.iter())
+    }
+
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.data.chunks(self.dims.x.max(1))
+    }
+
+    /// Neighbors of `pos` on the 4 cardinal directions that are inside the grid
+    pub fn neighbors(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.neighbors_in(pos, &Dir::CARDINAL)
+    }
+
+    /// Neighbors of `pos` on the given directions that are inside the grid
+    pub fn neighbors_in<'a>(&self, pos: Pos, dirs: &'a [Dir]) -> impl Iterator<Item = Pos> + 'a {
+        let dims = self.dims;
+        dirs.iter()
+            .filter(move |&&d| pos.can_move_by(d, dims))
+            .map(move |&d| pos + d)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            dims: self.dims,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Builds a grid of the given dims, filled with `value`
+    pub fn new(dims: Pos, value: T) -> Grid<T> {
+        Grid {
+            dims,
+            data: vec![value; dims.x * dims.y],
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// First position, in row-major order, that contains `value`
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.data
+            .iter()
+            .position(|v| v == value)
+            .map(|idx| self.pos(idx))
+    }
+
+    /// All positions, in row-major order, that contain `value`
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a {
+        self.iter_pos()
+            .filter(move |(_, v)| *v == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl Grid<char> {
+    /// Builds a grid of chars from the lines of the input
+    pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, "a char", Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(self.contains(pos), "{pos:?} outside of grid");
+        &self.data[self.idx(pos)]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(self.contains(pos), "{pos:?} outside of grid");
+        let idx = self.idx(pos);
+        &mut self.data[idx]
+    }
+}
+
+// Displays each row on its own line, without a trailing newline
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for v in row {
+                write!(f, "{v}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "#.S\n..#\nE.#\n";
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.dims, Pos { x: 3, y: 3 });
+        assert_eq!(grid[Pos { x: 2, y: 0 }], 'S');
+        assert_eq!(grid[Pos { x: 0, y: 2 }], 'E');
+        assert_eq!(grid.get(Pos { x: 3, y: 0 }), None);
+        assert_eq!(grid.to_string(), INPUT.trim_end());
+
+        let digits = Grid::parse_with("12\n34\n", "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(
+            digits.rows().map(|r| r.to_vec()).collect::<Vec<_>>(),
+            [[1, 2], [3, 4]]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let input = "...\n..\n";
+        let err = Grid::parse(input).unwrap_err().locate(input);
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a row with 3 cells");
+
+        let input = "12\n3x\n";
+        let err = Grid::parse_with(input, "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            err.locate(input).to_string(),
+            "line 2, column 2: expected a digit, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = Grid::parse(INPUT).unwrap();
+        assert_eq!(grid.find(&'S'), Some(Pos { x: 2, y: 0 }));
+        assert_eq!(grid.find(&'X'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            [Pos { x: 0, y: 0 }, Pos { x: 2, y: 1 }, Pos { x: 2, y: 2 }]
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(Pos { x: 3, y: 2 }, 0u8);
+        let mut corner = grid.neighbors(Pos { x: 0, y: 0 }).collect::<Vec<_>>();
+        corner.sort();
+        assert_eq!(corner, [Pos { x: 0, y: 1 }, Pos { x: 1, y: 0 }]);
+        assert_eq!(grid.neighbors(Pos { x: 1, y: 1 }).count(), 3);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::new(Pos { x: 2, y: 2 }, '.');
+        grid[Pos { x: 1, y: 0 }] = '#';
+        *grid.get_mut(Pos { x: 0, y: 1 }).unwrap() = '@';
+        assert_eq!(grid.to_string(), ".#\n@.");
+        assert_eq!(grid.pos(grid.idx(Pos { x: 0, y: 1 })), Pos { x: 0, y: 1 });
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of grid")]
+    fn test_index_outside() {
+        // Would be the first cell of the next row on the flat storage
+        let grid = Grid::new(Pos { x: 2, y: 2 }, '.');
+        let _ = grid[Pos { x: 2, y: 0 }];
+    }
+}
@@ -1,10 +1,45 @@
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::borrow::Cow;
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
-#[derive(Debug)]
-pub enum AoCResult {
-    None,
-    Str(String),
-    Int(i64),
+mod answer;
+pub mod days;
+#[cfg(any(test, feature = "reference"))]
+pub mod differential;
+pub mod generate;
+mod grid;
+pub mod math;
+pub mod par;
+pub mod params;
+pub mod parse;
+pub mod search;
+mod solver;
+pub mod stats;
+pub mod visual;
+
+pub use answer::{Answer, AoCError, AoCResult};
+pub use grid::Grid;
+pub use parse::ParseError;
+pub use solver::{Entry, PartRun, Parts, Run, Solver};
+
+/// Implemented years, with their days. Other years than 2024 have their days on a module of
+/// their own, like `year2023`
+pub const YEARS: &[(u16, &[Entry])] = &[
+    (2024, days::DAYS), // On `days`
+];
+
+/// Normalizes an input however it was saved: strips the byte order mark, converts CRLF line
+/// endings to LF and ends it with a single newline, without trailing whitespace. Inputs that
+/// are already normalized are borrowed
+pub fn normalize_input(input: &str) -> Cow<'_, str> {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let trimmed = input.trim_end();
+    if trimmed.is_empty() {
+        return Cow::Borrowed("");
+    }
+    if !trimmed.contains('\r') && input.len() == trimmed.len() + 1 && input.ends_with('\n') {
+        return Cow::Borrowed(input);
+    }
+    Cow::Owned(trimmed.replace("\r\n", "\n") + "\n")
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
@@ -19,15 +54,39 @@ pub struct Dir {
     pub y: isize,
 }
 
+/// Signed position, for coordinates that may fall outside of a grid
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct IPos {
+    pub x: isize,
+    pub y: isize,
+}
+
 impl Pos {
+    /// Moves by `by`, returning `None` if the result falls outside of `dim`
+    pub fn checked_add(&self, by: Dir, dim: Pos) -> Option<Pos> {
+        let x = self.x.checked_add_signed(by.x)?;
+        let y = self.y.checked_add_signed(by.y)?;
+        (x < dim.x && y < dim.y).then_some(Pos { x, y })
+    }
+
     pub fn can_move_by(&self, by: Dir, dim: Pos) -> bool {
-        ((by.x < 0 && self.x >= by.x.abs() as usize)
-            || (by.x >= 0 && self.x + (by.x as usize) < dim.x))
-            && ((by.y < 0 && self.y >= by.y.abs() as usize)
-                || (by.y >= 0 && self.y + (by.y as usize) < dim.y))
+        self.checked_add(by, dim).is_some()
+    }
+
+    /// Moves by `by` on a toroidal world of size `dim`, wrapping around the edges
+    pub fn wrapping_add(&self, by: Dir, dim: Pos) -> Pos {
+        let wrap = |v: usize, d: isize, limit: usize| {
+            ((v % limit) + d.rem_euclid(limit as isize) as usize) % limit
+        };
+        Pos {
+            x: wrap(self.x, by.x, dim.x),
+            y: wrap(self.y, by.y, dim.y),
+        }
     }
 }
 
+const NEGATIVE_POS: &str = "Pos moved to a negative coordinate";
+
 impl Add for Pos {
     type Output = Pos;
 
@@ -42,10 +101,11 @@ impl Add for Pos {
 impl Add<Dir> for Pos {
     type Output = Pos;
 
+    // Panics if a coordinate becomes negative, use `checked_add` to handle that case
     fn add(self, dir: Dir) -> Pos {
         Pos {
-            x: (self.x as isize + dir.x) as usize,
-            y: (self.y as isize + dir.y) as usize,
+            x: self.x.checked_add_signed(dir.x).expect(NEGATIVE_POS),
+            y: self.y.checked_add_signed(dir.y).expect(NEGATIVE_POS),
         }
     }
 }
@@ -61,10 +121,7 @@ impl AddAssign for Pos {
 
 impl AddAssign<Dir> for Pos {
     fn add_assign(&mut self, dir: Dir) {
-        *self = Self {
-            x: (self.x as isize + dir.x) as usize,
-            y: (self.y as isize + dir.y) as usize,
-        };
+        *self = *self + dir;
     }
 }
 
@@ -83,9 +140,9 @@ impl Sub<Dir> for Pos {
     type Output = Pos;
 
     fn sub(self, dir: Dir) -> Pos {
-        Pos {
-            x: (self.x as isize - dir.x) as usize,
-            y: (self.y as isize - dir.y) as usize,
+        self + Dir {
+            x: -dir.x,
+            y: -dir.y,
         }
     }
 }
@@ -101,10 +158,88 @@ impl SubAssign for Pos {
 
 impl SubAssign<Dir> for Pos {
     fn sub_assign(&mut self, dir: Dir) {
-        *self = Self {
-            x: (self.x as isize - dir.x) as usize,
-            y: (self.y as isize - dir.y) as usize,
-        };
+        *self = *self - dir;
+    }
+}
+
+impl From<Pos> for IPos {
+    fn from(pos: Pos) -> IPos {
+        IPos {
+            x: pos.x as isize,
+            y: pos.y as isize,
+        }
+    }
+}
+
+impl TryFrom<IPos> for Pos {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(pos: IPos) -> Result<Pos, Self::Error> {
+        Ok(Pos {
+            x: pos.x.try_into()?,
+            y: pos.y.try_into()?,
+        })
+    }
+}
+
+impl IPos {
+    /// Whether the position is inside a grid of size `dim`
+    pub fn inside(&self, dim: Pos) -> bool {
+        self.x >= 0 && self.y >= 0 && (self.x as usize) < dim.x && (self.y as usize) < dim.y
+    }
+
+    /// Converts to a `Pos`, if inside a grid of size `dim`
+    pub fn to_pos(&self, dim: Pos) -> Option<Pos> {
+        self.inside(dim).then_some(Pos {
+            x: self.x as usize,
+            y: self.y as usize,
+        })
+    }
+}
+
+impl Add<Dir> for IPos {
+    type Output = IPos;
+
+    fn add(self, dir: Dir) -> IPos {
+        IPos {
+            x: self.x + dir.x,
+            y: self.y + dir.y,
+        }
+    }
+}
+
+impl AddAssign<Dir> for IPos {
+    fn add_assign(&mut self, dir: Dir) {
+        *self = *self + dir;
+    }
+}
+
+impl Sub<Dir> for IPos {
+    type Output = IPos;
+
+    fn sub(self, dir: Dir) -> IPos {
+        IPos {
+            x: self.x - dir.x,
+            y: self.y - dir.y,
+        }
+    }
+}
+
+impl SubAssign<Dir> for IPos {
+    fn sub_assign(&mut self, dir: Dir) {
+        *self = *self - dir;
+    }
+}
+
+// The difference between two positions is the direction from one to the other
+impl Sub for IPos {
+    type Output = Dir;
+
+    fn sub(self, other: IPos) -> Dir {
+        Dir {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
     }
 }
 
@@ -114,14 +249,21 @@ pub enum Rotation {
 }
 
 impl Dir {
+    pub const UP: Dir = Dir { x: 0, y: -1 };
+    pub const DOWN: Dir = Dir { x: 0, y: 1 };
+    pub const LEFT: Dir = Dir { x: -1, y: 0 };
+    pub const RIGHT: Dir = Dir { x: 1, y: 0 };
+    /// The 4 cardinal directions
+    pub const CARDINAL: [Dir; 4] = [Dir::DOWN, Dir::LEFT, Dir::UP, Dir::RIGHT];
+
     pub fn rotate(&self, towards: Rotation) -> Dir {
         match towards {
             Rotation::Clockwise => Dir {
                 y: self.x,
-                x: self.y * -1,
+                x: -self.y,
             },
             Rotation::CounterClockwise => Dir {
-                y: self.x * -1,
+                y: -self.x,
                 x: self.y,
             },
         }
@@ -138,3 +280,73 @@ impl Sub for Dir {
         }
     }
 }
+
+impl Mul<isize> for Dir {
+    type Output = Dir;
+
+    fn mul(self, n: isize) -> Dir {
+        Dir {
+            x: self.x * n,
+            y: self.y * n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIMS: Pos = Pos { x: 3, y: 2 };
+
+    #[test]
+    fn test_normalize_input() {
+        assert_eq!(normalize_input("1 2\n3 4\n"), "1 2\n3 4\n");
+        assert!(matches!(normalize_input("1 2\n3 4\n"), Cow::Borrowed(_)));
+        assert_eq!(normalize_input("\u{feff}1 2\r\n3 4\r\n\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize_input("1 2\n\n3 4"), "1 2\n\n3 4\n");
+        assert_eq!(normalize_input("1 2 \n \n\n"), "1 2\n");
+        assert_eq!(normalize_input("\r\n"), "");
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let origin = Pos { x: 0, y: 0 };
+        assert_eq!(origin.checked_add(Dir::LEFT, DIMS), None);
+        assert_eq!(origin.checked_add(Dir::UP, DIMS), None);
+        assert_eq!(
+            origin.checked_add(Dir::RIGHT, DIMS),
+            Some(Pos { x: 1, y: 0 })
+        );
+        assert_eq!(Pos { x: 2, y: 1 }.checked_add(Dir::RIGHT, DIMS), None);
+        assert_eq!(Pos { x: 2, y: 1 }.checked_add(Dir::DOWN, DIMS), None);
+        assert!(!origin.can_move_by(Dir { x: 1, y: 2 }, DIMS));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_negative() {
+        let _ = Pos { x: 0, y: 0 } + Dir::LEFT;
+    }
+
+    #[test]
+    fn test_wrapping_add() {
+        let pos = Pos { x: 0, y: 1 };
+        assert_eq!(pos.wrapping_add(Dir::LEFT, DIMS), Pos { x: 2, y: 1 });
+        assert_eq!(pos.wrapping_add(Dir::DOWN, DIMS), Pos { x: 0, y: 0 });
+        assert_eq!(
+            pos.wrapping_add(Dir { x: -7, y: 5 } * 3, DIMS),
+            Pos { x: 0, y: 0 }
+        );
+    }
+
+    #[test]
+    fn test_ipos() {
+        let pos = IPos::from(Pos { x: 1, y: 0 });
+        assert_eq!(pos + Dir::UP, IPos { x: 1, y: -1 });
+        assert!(!(pos + Dir::UP).inside(DIMS));
+        assert_eq!((pos + Dir::DOWN).to_pos(DIMS), Some(Pos { x: 1, y: 1 }));
+        assert_eq!(Pos::try_from(pos - Dir::RIGHT), Ok(Pos { x: 0, y: 0 }));
+        assert!(Pos::try_from(pos + Dir::LEFT * 2).is_err());
+        assert_eq!(IPos { x: 4, y: 2 } - pos, Dir { x: 3, y: 2 });
+    }
+}
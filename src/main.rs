@@ -1,125 +1,568 @@
-use std::{env, fs, process, time};
+use std::io::{self, Read};
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs, process};
 
-use aoc2024::AoCResult;
+use aoc2024::params::Overrides;
+use aoc2024::{
+    generate, normalize_input, par, visual, Answer, AoCError, Entry, ParseError, PartRun, Parts,
+    Run, YEARS,
+};
+use cli::{Command, Config, Format, Visualize};
+use report::Record;
 
-// Command line argument parsing
-struct Config {
-    day: u16,
-    file_path: String,
+mod bench;
+mod cli;
+mod report;
+mod scaffold;
+mod verify;
+
+// Entry of `day` of `year`, which must be implemented
+fn entry(year: u16, day: u16) -> &'static Entry {
+    let (_, days) = YEARS.iter().find(|(y, _)| *y == year).unwrap();
+    days.iter().find(|entry| entry.day == day).unwrap()
 }
 
-impl Config {
-    fn build(args: &[String]) -> Result<Config, &'static str> {
-        let mut use_test_file = false;
-        let mut day: Option<&String> = None;
-        let mut file_path: Option<&String> = None;
-    
-        // Poor man's argument parsing...
-        for arg in args.into_iter().skip(1) {
-            if arg == "-t" {
-                use_test_file = true;
-            } else if day.is_none()  {
-                day = Some(arg);
-            } else {
-                file_path = Some(arg);
+// Parts solved together with part one have their time and stats on it
+fn print_part(part: &PartRun, desc: &str, shared: bool, quiet: bool) {
+    if quiet {
+        match &part.answer {
+            Ok(val) => println!("{val}"),
+            Err(err) => eprintln!("{desc}: {err}"),
+        }
+        return;
+    }
+    if shared {
+        print!("{desc} (with part one): ");
+    } else {
+        print!("{desc} ({:?}): ", part.time);
+    }
+    match &part.answer {
+        Ok(Answer::Grid(val)) => println!("\n{val}"),
+        Ok(val) => println!("{val}"),
+        Err(err) => println!("{err}"),
+    }
+    if !part.stats.is_empty() {
+        println!("  {}", part.stats);
+    }
+}
+
+// Runs `entry` `repeat` times with the given parameters, keeping the answers of the last run
+// and the mean times
+fn run_repeated(
+    entry: &Entry,
+    input: &str,
+    parts: Parts,
+    params: &Overrides,
+    repeat: u32,
+) -> Result<Run, ParseError> {
+    let time = |part: &Option<PartRun>| part.as_ref().map_or(Duration::ZERO, |p| p.time);
+    let (mut parse, mut one, mut two) = (Duration::ZERO, Duration::ZERO, Duration::ZERO);
+    let mut run = entry.run_with(input, parts, params)?;
+    for i in 0..repeat {
+        if i > 0 {
+            run = entry.run_with(input, parts, params)?;
+        }
+        parse += run.parse_time;
+        one += time(&run.part_one);
+        two += time(&run.part_two);
+    }
+    run.parse_time = parse / repeat;
+    for (part, time) in [(&mut run.part_one, one), (&mut run.part_two, two)] {
+        if let Some(part) = part {
+            part.time = time / repeat;
+        }
+    }
+    Ok(run)
+}
+
+// Prints a record, on the machine readable formats
+fn print_record(format: Format, record: &Record) {
+    match format {
+        Format::Text => (),
+        Format::Json => println!("{}", record.to_json()),
+        Format::Csv => println!("{}", record.to_csv()),
+    }
+}
+
+// Diagnostic of a parse error of `input`, which was located on the normalized input that
+// was parsed, so that the line quoted is the one the column refers to
+fn diagnostic(err: &ParseError, input: &str) -> String {
+    err.report(&normalize_input(input))
+}
+
+// Prints the answers of a day, from the run on the input at `input_path`. Returns None if it
+// couldn't be run
+fn print_day(
+    day: u16,
+    input_path: &str,
+    input: &str,
+    run: Result<Run, ParseError>,
+    config: &Config,
+) -> Option<Run> {
+    let text = config.format == Format::Text;
+    let mut record = Record {
+        day,
+        part: Err(String::new()),
+        parse_time: Duration::ZERO,
+        shared: false,
+        input_path,
+        input_hash: Some(report::fnv1a(input.as_bytes())),
+    };
+    if text && !config.quiet {
+        // Labelled with the input when it was given, as there may be several
+        if config.inputs.is_empty() {
+            println!("Day {day}:");
+        } else {
+            println!("Day {day}, {input_path}:");
+        }
+    }
+    let run = match run {
+        Ok(run) => run,
+        Err(err) => {
+            // Point at the offending line instead of solving
+            if text && !config.quiet {
+                println!("Invalid input");
             }
+            record.part = Err(format!("Invalid input: {err}"));
+            print_record(config.format, &record);
+            eprintln!("{}", diagnostic(&err, input));
+            return None;
         }
-    
-        let day = match day {
-            None => "".parse::<u16>(), // Force an error
-            Some(s) => s.parse::<u16>(),
+    };
+
+    if text && !config.quiet {
+        println!("Parse ({:?})", run.parse_time);
+    }
+    record.parse_time = run.parse_time;
+    let parts = [
+        (1, "Part one", &run.part_one),
+        (2, "Part two", &run.part_two),
+    ];
+    for (n, desc, part) in parts {
+        let Some(part) = part else { continue };
+        record.shared = run.shared && n == 2;
+        if text {
+            print_part(part, desc, record.shared, config.quiet);
+        } else {
+            record.part = Ok((n, part));
+            print_record(config.format, &record);
+        }
+    }
+    Some(run)
+}
+
+// Reads an input file, or stdin if it is '-'
+fn read_input(file_path: &str) -> Result<String, String> {
+    if file_path == "-" {
+        let mut input = String::new();
+        io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|err| format!("Couldn't read stdin: {err}"))?;
+        return Ok(input);
+    }
+    fs::read_to_string(file_path).map_err(|_| format!("Couldn't read file: {file_path}"))
+}
+
+// Longest an animation takes, playing its frames faster if needed
+const ANIMATION_TIME: Duration = Duration::from_secs(10);
+
+// Exports the frames recorded while running a day on the input at `input_path`. Images are
+// saved on a directory named after the input
+fn export_frames(
+    frames: &[visual::Frame],
+    input_path: &str,
+    visualize: Visualize,
+) -> Result<(), String> {
+    if frames.is_empty() {
+        return Ok(());
+    }
+    let ext = match visualize {
+        Visualize::Ansi => {
+            let delay = (ANIMATION_TIME / frames.len() as u32).min(Duration::from_millis(100));
+            return visual::play(frames, &mut io::stdout(), delay)
+                .map_err(|err| format!("Couldn't play the frames: {err}"));
+        }
+        Visualize::Text => {
+            println!("{}", visual::to_text(frames));
+            return Ok(());
+        }
+        Visualize::Ppm => "ppm",
+        Visualize::Png => "png",
+    };
+    let name = match input_path {
+        "-" => "stdin",
+        path => Path::new(path)
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or(path),
+    };
+    let dir = format!("visualize/{name}");
+    fs::create_dir_all(&dir).map_err(|err| format!("Couldn't create {dir}: {err}"))?;
+    for (i, frame) in frames.iter().enumerate() {
+        // Images of about 500 pixels on their largest side
+        let scale = (500 / frame.grid.width().max(frame.grid.height()).max(1)).clamp(1, 10);
+        let image = match visualize {
+            Visualize::Ppm => visual::to_ppm(frame, scale),
+            _ => visual::to_png(frame, scale),
         };
-    
-        if day.is_err() {
-            return Err("usage: aoc day [-t] [file]\n\
-                \tday - Day to run\n\
-                \t-t - Test switch, to use 'data/input[day]Test' file for the data if none specified\n\
-                \tfile - data file, use 'data/input[day]' if none specified");
-        }
-        let day = day.unwrap();
-    
-        let file_path = match file_path {
-            None => format!("data/input{}{}", day, if use_test_file {"Test"} else {""}),
-            Some(s) => s.clone(),
+        let file = format!("{dir}/frame{:05}.{ext}", i + 1);
+        fs::write(&file, image).map_err(|err| format!("Couldn't write {file}: {err}"))?;
+    }
+    eprintln!("{} frames saved on {dir}", frames.len());
+    Ok(())
+}
+
+// Benchmarks the days, printing a table with their statistics. Returns whether all days were
+// run, without regressions against the baseline
+fn run_bench(config: &Config, options: &bench::Options) -> bool {
+    let baseline = match &options.baseline {
+        Some(file) => match fs::read_to_string(file) {
+            Ok(s) => Some(bench::parse_baseline(&s).unwrap_or_else(|err| {
+                eprintln!("{err}");
+                process::exit(2);
+            })),
+            Err(_) => {
+                eprintln!("Couldn't read baseline: {file}");
+                process::exit(2);
+            }
+        },
+        None => None,
+    };
+
+    let mut benches = Vec::new();
+    let mut failed = false;
+    for &day in &config.days {
+        let entry = entry(config.year, day);
+        let res = match config.input_paths(day).as_deref() {
+            Ok([path]) => read_input(path),
+            Ok([]) => continue,
+            Ok(_) => Err("bench takes a single input for each day".to_string()),
+            Err(err) => Err(err.clone()),
         };
+        let res = res.and_then(|input| {
+            eprintln!("Day {day}...");
+            bench::bench(entry, &input, config.parts, options)
+                .map_err(|err| format!("Invalid input: {err}"))
+        });
+        match res {
+            Ok(bench) => benches.push(bench),
+            Err(err) => {
+                eprintln!("Day {day}: {err}");
+                failed = true;
+            }
+        }
+    }
 
-        Ok(Config { day, file_path })
+    let (table, regressions) = bench::table(&benches, baseline.as_ref(), options.threshold);
+    println!("{table}");
+    if regressions > 0 {
+        println!("\n{regressions} regressions above {}%", options.threshold);
     }
-    
+    if let Some(file) = &options.save {
+        if let Err(err) = fs::write(file, bench::format_baseline(&benches)) {
+            eprintln!("Couldn't save baseline {file}: {err}");
+            failed = true;
+        }
+    }
+    !failed && regressions == 0
 }
 
-// Expands for each day
-macro_rules! expand_days {
-    ( $( $day_num:expr => $mod:ident ),* ) => {
-        $(
-            mod $mod;
-        )*
-
-        fn run_day_part(fn_part: fn(&str) -> AoCResult, input: &str, desc: &str) {
-            let start = time::Instant::now();
-            let res = fn_part(input);
-            let elapsed = start.elapsed();
-            print!("{desc} ({elapsed:?}): ");
-            match res {
-                AoCResult::None => { println!("No value"); },
-                AoCResult::Str(val) => { println!("{val}"); },
-                AoCResult::Int(val) => { println!("{val}"); },
+// Verifies the answers of all the input files of the days against the expected ones, printing
+// the outcome of each part. Returns whether none failed
+fn run_verify(config: &Config) -> bool {
+    let data = cli::data_dir(config.year);
+    let answers_file = format!("{data}/answers");
+    let answers = fs::read_to_string(&answers_file)
+        .map_err(|_| format!("Couldn't read file: {answers_file}"))
+        .and_then(|s| verify::parse_answers(&s))
+        .unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(2);
+        });
+    let files = fs::read_dir(&data)
+        .map(|dir| {
+            dir.filter_map(|file| file.ok()?.file_name().into_string().ok())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for &day in &config.days {
+        let entry = entry(config.year, day);
+        for file in verify::input_files(day, &files, &answers) {
+            let Ok(input) = fs::read_to_string(format!("{data}/{file}")) else {
+                println!("Day {day}, {file}: FAIL, couldn't read file");
+                failed += 1;
+                continue;
+            };
+            let expected = answers.get(&(day, file.clone()));
+            let params = |i: usize| {
+                let expected = expected.and_then(|answers| answers[i].as_ref());
+                expected.map_or(&[][..], |expected| &expected.params[..])
+            };
+            // Both parts are solved together, unless they have different parameters
+            let runs = match config.parts {
+                Parts::Both if params(0) != params(1) => {
+                    vec![(Parts::One, params(0)), (Parts::Two, params(1))]
+                }
+                Parts::Two => vec![(Parts::Two, params(1))],
+                parts => vec![(parts, params(0))],
+            };
+            let mut results = [None, None];
+            for (parts, params) in runs {
+                match entry.run_with(&input, parts, params) {
+                    Ok(run) => {
+                        for (result, part) in results.iter_mut().zip([run.part_one, run.part_two]) {
+                            if let Some(part) = part {
+                                *result = Some(part.answer);
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        let err = AoCError::Parse(err);
+                        let has_parts = [parts.has_one(), parts.has_two()];
+                        for (result, has_part) in results.iter_mut().zip(has_parts) {
+                            if has_part {
+                                *result = Some(Err(err.clone()));
+                            }
+                        }
+                    }
+                }
+            }
+
+            for (i, result) in results.iter().enumerate() {
+                let expected = expected
+                    .and_then(|answers| answers[i].as_ref())
+                    .map(|expected| expected.answer.as_str());
+                let Some(outcome) = result
+                    .as_ref()
+                    .and_then(|result| verify::Outcome::new(expected, result))
+                else {
+                    continue;
+                };
+                match outcome {
+                    verify::Outcome::Pass => passed += 1,
+                    verify::Outcome::Fail { .. } => failed += 1,
+                    verify::Outcome::Missing(_) => missing += 1,
+                }
+                if !(config.quiet && outcome == verify::Outcome::Pass) {
+                    println!("Day {day}, {file}, part {}: {outcome}", i + 1);
+                }
             }
         }
+    }
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    failed == 0
+}
 
-        fn run_day(day: u16, input: &str) {
-            match day {
-                $(
-                    $day_num => {
-                        println!("Day {day}:");
-                        run_day_part($mod::solve_part_one, &input, "Part one");
-                        run_day_part($mod::solve_part_two, &input, "Part two");
-                    },
-                )*
-                _ => {
-                    println!("Day {day} not implemented.");
-                },
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    let available = YEARS
+        .iter()
+        .map(|(year, days)| (*year, days.iter().map(|entry| entry.day).collect()))
+        .collect::<Vec<_>>();
+    let config = match Config::build(&args, &available) {
+        Ok(Command::Run(config)) => config,
+        Ok(Command::Bench(config, options)) => {
+            if !run_bench(&config, &options) {
+                process::exit(1);
+            }
+            return;
+        }
+        Ok(Command::Verify(config)) => {
+            if !run_verify(&config) {
+                process::exit(1);
+            }
+            return;
+        }
+        Ok(Command::New(year, day)) => match scaffold::new_day(year, day) {
+            Ok(files) => {
+                println!("Day {day} of {year} created: {}", files.join(", "));
+                return;
             }
+            Err(err) => {
+                eprintln!("{err}");
+                process::exit(1);
+            }
+        },
+        Ok(Command::Gen(day, options)) => {
+            let generator = generate::generator(day).unwrap();
+            let size = options.size.unwrap_or(generator.default_size);
+            print!("{}", generator.generate(options.seed, size));
+            return;
+        }
+        Ok(Command::Help) => {
+            println!("{}", cli::usage(&available));
+            return;
+        }
+        Err(err) => {
+            eprintln!("{err}\nUse --help for the usage and the implemented days");
+            process::exit(2);
         }
     };
-}
 
-expand_days!(1 => day1, 
-             2 => day2,
-             3 => day3,
-             4 => day4,
-             5 => day5,
-             6 => day6,
-             7 => day7,
-             8 => day8,
-             9 => day9,
-             10 => day10,
-             11 => day11,
-             12 => day12,
-             13 => day13,
-             14 => day14,
-             15 => day15,
-             16 => day16,
-             17 => day17,
-             18 => day18,
-             19 => day19,
-             20 => day20,
-             21 => day21,
-             22 => day22,
-             23 => day23,
-             24 => day24,
-             25 => day25
-            );
+    // Each parameter must be of one of the days, which ignore those of the others
+    let days_params = config
+        .days
+        .iter()
+        .flat_map(|&day| entry(config.year, day).params.iter().copied())
+        .collect::<Vec<_>>();
+    if let Some((name, _)) = config
+        .params
+        .iter()
+        .find(|(name, _)| !days_params.contains(&name.as_str()))
+    {
+        eprintln!("None of the days has a parameter '{name}'");
+        process::exit(2);
+    }
+    for &day in &config.days {
+        if let Err(err) = entry(config.year, day).check_params(&config.params) {
+            eprintln!("Day {day}: invalid parameter, {err}");
+            process::exit(2);
+        }
+    }
 
-fn main() {
-    let args = env::args().collect::<Vec<String>>();
-    let config = Config::build(&args).unwrap_or_else(|err| {
-        println!("{err}");
-        process::exit(1);
+    if config.format == Format::Csv {
+        println!("{}", Record::CSV_HEADER);
+    }
+    // Each day is run on each of its inputs
+    let mut jobs = Vec::new();
+    for &day in &config.days {
+        let paths = config.input_paths(day).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(2);
+        });
+        jobs.extend(paths.into_iter().map(|path| (day, path)));
+    }
+    if jobs.is_empty() {
+        eprintln!("No input files for the days");
+        process::exit(2);
+    }
+
+    // The days are run first, in parallel with the `parallel` feature, and printed in order
+    let runs = par::map(&jobs, |(day, path)| {
+        let input = read_input(path)?;
+        let entry = entry(config.year, *day);
+        let run = || run_repeated(entry, &input, config.parts, &config.params, config.repeat);
+        let (run, frames) = match config.visualize {
+            Some(_) => visual::recorded(run),
+            None => (run(), Vec::new()),
+        };
+        Ok::<_, String>((input, run, frames))
     });
+    let mut times = Vec::new();
+    let mut failed = false;
+    for ((day, path), res) in jobs.iter().zip(runs) {
+        let day = *day;
+        let run = match res {
+            Ok((input, run, frames)) => {
+                let run = print_day(day, path, &input, run, &config);
+                if let Some(visualize) = config.visualize {
+                    if let Err(err) = export_frames(&frames, path, visualize) {
+                        eprintln!("Day {day}: {err}");
+                        failed = true;
+                    }
+                }
+                run
+            }
+            Err(err) => {
+                if config.format == Format::Text {
+                    eprintln!("Day {day}: {err}");
+                }
+                let record = Record {
+                    day,
+                    part: Err(err),
+                    parse_time: Duration::ZERO,
+                    shared: false,
+                    input_path: path,
+                    input_hash: None,
+                };
+                print_record(config.format, &record);
+                None
+            }
+        };
+        failed |= run.is_none();
+        times.push((day, run.as_ref().map(report::part_times)));
+    }
+
+    // Several days are summarized on a table at the end
+    if times.len() > 1 && config.format == Format::Text && !config.quiet {
+        println!("\n{}", report::runtime_table(&times));
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2024::days::DAYS;
 
-    let input = fs::read_to_string(&config.file_path)
-        .expect(&format!("Couldn't read file: {0}", config.file_path));
+    #[test]
+    fn test_registry() {
+        assert!(DAYS.iter().map(|entry| entry.day).eq(1..=25));
+        assert!(YEARS.iter().any(|&(year, _)| year == cli::DEFAULT_YEAR));
+        assert!(YEARS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_run_repeated() {
+        let input = include_str!("../data/input1Test");
+        let run = run_repeated(&DAYS[0], input, Parts::Two, &[], 3).unwrap();
+        assert!(run.part_one.is_none());
+        assert_eq!(run.part_two.unwrap().answer.unwrap(), 31);
+    }
+
+    #[test]
+    fn test_windows_inputs() {
+        // Saved with a byte order mark and CRLF line endings, as on Windows
+        for entry in DAYS {
+            let input = fs::read_to_string(format!("data/input{}Test", entry.day)).unwrap();
+            let windows = format!("\u{feff}{}", input.replace('\n', "\r\n"));
+            let answers = |input: &str| {
+                let run = entry.run(input, Parts::Both).unwrap();
+                [run.part_one, run.part_two].map(|part| part.unwrap().answer)
+            };
+            assert_eq!(answers(&input), answers(&windows), "day {}", entry.day);
+        }
+
+        // Diagnostics quote the line as it was parsed
+        let input = "\u{feff}3   4\r\n4   x\r\n";
+        let err = DAYS[0].run(input, Parts::Both).unwrap_err();
+        assert!(diagnostic(&err, input).ends_with("2 | 4   x\n  |     ^"));
+    }
+
+    // Solves a part of `day` of `year` with the given parameters and checks its answer
+    fn check_answer(
+        year: u16,
+        day: u16,
+        part: u8,
+        input: &str,
+        expected: &str,
+        params: &[(&str, &str)],
+    ) {
+        let entry = entry(year, day);
+        let parts = if part == 1 { Parts::One } else { Parts::Two };
+        let params = params
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        let run = entry.run_with(input, parts, &params).unwrap();
+        let part = if part == 1 {
+            run.part_one
+        } else {
+            run.part_two
+        };
+        assert_eq!(part.unwrap().answer.unwrap().to_string(), expected);
+    }
+
+    // A test for each expected answer on the answers file of each year, generated by build.rs
+    #[allow(non_snake_case)]
+    mod answers {
+        use super::check_answer;
 
-    run_day(config.day, &input);
+        include!(concat!(env!("OUT_DIR"), "/answer_tests.rs"));
+    }
 }
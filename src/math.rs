@@ -0,0 +1,165 @@
+//! Number theory on integers: greatest common divisors, modular inverses and powers, and the
+//! chinese remainder theorem. The modular operations compute their products on 128 bits, so
+//! they don't overflow for any `i64` arguments.
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0
+pub fn gcd(a: i64, b: i64) -> i64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a as i64
+}
+
+/// Least common multiple, never negative. 0 if either is 0
+pub fn lcm(a: i64, b: i64) -> i64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Extended Euclid's algorithm: the gcd of `a` and `b`, never negative, and x and y such that
+/// `a * x + b * y = gcd`
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// Inverse of `a` modulo `m`, on `0..m`, if they are coprime and `m` is positive
+pub fn mod_inv(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// `base` to the power of `exp` modulo `m`, on `0..m`, if `m` is positive
+pub fn mod_pow(base: i64, mut exp: u64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut res = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            res = res * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    Some(res as i64)
+}
+
+/// Chinese remainder theorem: the solution of `x = residue (mod modulus)` for each of the
+/// congruences, with moduli that don't need to be coprime. Returns the lowest non negative
+/// solution and the lcm of the moduli, the period of the solutions, or None if there's no
+/// solution or the moduli aren't positive. The lcm must fit on an `i64`
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut res, mut period) = (0i128, 1i128);
+    for &(residue, modulus) in congruences {
+        if modulus <= 0 {
+            return None;
+        }
+        let (residue, modulus) = ((residue as i128).rem_euclid(modulus as i128), modulus);
+        let g = gcd(period as i64, modulus) as i128;
+        let diff = residue - res;
+        if diff % g != 0 {
+            return None;
+        }
+        // res + period * k = residue (mod modulus), for k modulo modulus / g
+        let step = modulus as i128 / g;
+        let inv = mod_inv((period / g % step) as i64, step as i64)? as i128;
+        let k = (diff / g).rem_euclid(step) * inv % step;
+        res += period * k;
+        period *= step;
+        res = res.rem_euclid(period);
+    }
+    Some((res as i64, period as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, -5), 5);
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(i64::MIN, i64::MIN / 2), -(i64::MIN / 2));
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(lcm(101, 103), 10403);
+    }
+
+    #[test]
+    fn test_ext_gcd() {
+        for (a, b) in [
+            (240, 46),
+            (-240, 46),
+            (46, -240),
+            (7, 0),
+            (0, -7),
+            (0, 0),
+            (17, 17),
+        ] {
+            let (g, x, y) = ext_gcd(a, b);
+            assert_eq!(g, gcd(a, b), "{a}, {b}");
+            assert_eq!(a * x + b * y, g, "{a}, {b}");
+        }
+    }
+
+    #[test]
+    fn test_mod_inv() {
+        assert_eq!(mod_inv(3, 11), Some(4));
+        assert_eq!(mod_inv(-3, 11), Some(7));
+        assert_eq!(mod_inv(10, 17).map(|inv| inv * 10 % 17), Some(1));
+        assert_eq!(mod_inv(6, 9), None);
+        assert_eq!(mod_inv(5, 1), Some(0));
+        assert_eq!(mod_inv(3, 0), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 10, 1000), Some(24));
+        assert_eq!(mod_pow(-2, 3, 5), Some(2));
+        assert_eq!(mod_pow(7, 0, 13), Some(1));
+        assert_eq!(mod_pow(7, 0, 1), Some(0));
+        assert_eq!(mod_pow(3, 5, 0), None);
+        // Fermat's little theorem, with products above 64 bits
+        let p = 1_000_000_000_000_000_003;
+        assert_eq!(mod_pow(123_456_789_123, p as u64 - 1, p), Some(1));
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli that aren't coprime
+        assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 5), (12, 7)]), Some((19, 35)));
+        assert_eq!(
+            crt(&[(7, 101), (3, 103)]).map(|(x, _)| (x % 101, x % 103)),
+            Some((7, 3))
+        );
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(1, 0)]), None);
+    }
+}
@@ -0,0 +1,134 @@
+//! Parallel maps over slices, on scoped threads with the `parallel` feature and sequential
+//! without it. Results are always in the order of the items, so output doesn't depend on
+//! the scheduling. The stats recorded by the workers are added to those of the calling thread.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::stats;
+
+// Threads to use, a single one without the `parallel` feature
+fn threads() -> usize {
+    if cfg!(feature = "parallel") {
+        thread::available_parallelism().map_or(1, |n| n.get())
+    } else {
+        1
+    }
+}
+
+fn join<R>(handle: thread::ScopedJoinHandle<'_, R>) -> R {
+    // Propagate the panics of the workers, with their message
+    handle
+        .join()
+        .unwrap_or_else(|err| std::panic::resume_unwind(err))
+}
+
+// Joins a worker, adding the stats it recorded to those of this thread
+fn join_merged<R>(handle: thread::ScopedJoinHandle<'_, (R, stats::Stats)>) -> R {
+    let (res, stats) = join(handle);
+    stats::merge(&stats);
+    res
+}
+
+/// Maps `f` over `items`. With the `parallel` feature, the threads take the next item as
+/// they finish the previous one, so items that take very different times are balanced
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_on(threads(), items, f)
+}
+
+fn map_on<T: Sync, R: Send>(threads: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    if threads <= 1 || items.len() <= 1 {
+        return items.iter().map(f).collect();
+    }
+    let next = AtomicUsize::new(0);
+    let work = || {
+        let mut res = Vec::new();
+        loop {
+            let idx = next.fetch_add(1, Ordering::Relaxed);
+            let Some(item) = items.get(idx) else {
+                return res;
+            };
+            res.push((idx, f(item)));
+        }
+    };
+    let mut res = thread::scope(|scope| {
+        let handles = (0..threads.min(items.len()))
+            .map(|_| scope.spawn(|| stats::collect(work)))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(join_merged)
+            .collect::<Vec<_>>()
+    });
+    res.sort_unstable_by_key(|&(idx, _)| idx);
+    res.into_iter().map(|(_, r)| r).collect()
+}
+
+/// Maps `f` over contiguous chunks of `items`, one for each thread with the `parallel`
+/// feature, or a single one without it. For work that needs scratch space or accumulates
+/// results, which can be set up once for each chunk
+pub fn map_chunks<T: Sync, R: Send>(items: &[T], f: impl Fn(&[T]) -> R + Sync) -> Vec<R> {
+    map_chunks_on(threads(), items, f)
+}
+
+fn map_chunks_on<T: Sync, R: Send>(
+    threads: usize,
+    items: &[T],
+    f: impl Fn(&[T]) -> R + Sync,
+) -> Vec<R> {
+    if threads <= 1 || items.len() <= 1 {
+        return vec![f(items)];
+    }
+    let size = items.len().div_ceil(threads);
+    thread::scope(|scope| {
+        let handles = items
+            .chunks(size)
+            .map(|chunk| scope.spawn(|| stats::collect(|| f(chunk))))
+            .collect::<Vec<_>>();
+        handles.into_iter().map(join_merged).collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map() {
+        let items = (0..100u64).collect::<Vec<_>>();
+        let doubled = (0..200).step_by(2).collect::<Vec<_>>();
+        assert_eq!(map(&items, |&v| v * 2), doubled);
+        assert_eq!(map_on(4, &items, |&v| v * 2), doubled);
+        assert!(map_on(4, &[] as &[u64], |&v| v).is_empty());
+    }
+
+    #[test]
+    fn test_map_chunks() {
+        let items = (0..100u64).collect::<Vec<_>>();
+        assert_eq!(map_chunks(&items, |chunk| chunk.to_vec()).concat(), items);
+        let chunks = map_chunks_on(4, &items, |chunk| chunk.to_vec());
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.concat(), items);
+        let sums = map_chunks_on(3, &items, |chunk| chunk.iter().sum::<u64>());
+        assert_eq!(sums.iter().sum::<u64>(), 4950);
+    }
+
+    #[test]
+    fn test_stats() {
+        let items = (0..10).collect::<Vec<_>>();
+        let (_, stats) = stats::collect(|| {
+            map_on(4, &items, |_| stats::count("items"));
+            map_chunks_on(4, &items, |chunk| stats::add("items", chunk.len() as u64));
+        });
+        if cfg!(feature = "stats") {
+            assert_eq!(stats.get("items").unwrap().count, 20);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "item 7")]
+    fn test_map_panic() {
+        let items = (0..10).collect::<Vec<_>>();
+        map_on(4, &items, |&v| assert!(v != 7, "item {v}"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Parameters of the puzzles, constants like the size of a grid that differ between the
+//! examples and the real inputs.
+//!
+//! Each solver declares its parameters with `params!`, as a struct with a default for the real
+//! inputs, so that tests can set them directly. They can also be overridden by name from their
+//! text, like `dims=7x7` from the runner's `--param`.
+
+use crate::{parse, ParseError, Pos};
+
+/// Parameters overridden by name, with the text of their values
+pub type Overrides = [(String, String)];
+
+/// Value of a parameter, parsed from its text
+pub trait Param: Sized {
+    fn parse_param(s: &str) -> Result<Self, ParseError>;
+}
+
+macro_rules! num_param {
+    ($($ty:ty),*) => {
+        $(
+            impl Param for $ty {
+                fn parse_param(s: &str) -> Result<Self, ParseError> {
+                    parse::num(s)
+                }
+            }
+        )*
+    };
+}
+
+num_param!(u8, u16, u32, u64, usize, i64);
+
+// Sizes are written like `7x7`, the width first, and can't be empty
+impl Param for Pos {
+    fn parse_param(s: &str) -> Result<Self, ParseError> {
+        let err = || ParseError::new(s, "a size like 7x7");
+        let (x, y) = s.split_once('x').ok_or_else(err)?;
+        let size = Pos {
+            x: parse::num(x).map_err(|_| err())?,
+            y: parse::num(y).map_err(|_| err())?,
+        };
+        if size.x == 0 || size.y == 0 {
+            return Err(ParseError::new(s, "a size of at least 1x1"));
+        }
+        Ok(size)
+    }
+}
+
+/// Parameters of a solver, `()` for those without any
+pub trait Params: Default {
+    /// Names of the parameters, in order
+    const NAMES: &'static [&'static str];
+
+    /// Sets the parameter `name` from the text of its value
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParseError>;
+
+    /// Defaults, with the parameters overridden by name
+    fn with(overrides: &Overrides) -> Result<Self, ParseError> {
+        let mut params = Self::default();
+        for (name, value) in overrides {
+            params.set(name, value).map_err(|mut err| {
+                if err.found == *value {
+                    err.expected = format!("{} for {name}", err.expected);
+                }
+                err
+            })?;
+        }
+        Ok(params)
+    }
+}
+
+impl Params for () {
+    const NAMES: &'static [&'static str] = &[];
+
+    fn set(&mut self, name: &str, _: &str) -> Result<(), ParseError> {
+        Err(unknown::<Self>(name))
+    }
+}
+
+/// Error for a parameter `name` that `P` doesn't have
+pub fn unknown<P: Params>(name: &str) -> ParseError {
+    if P::NAMES.is_empty() {
+        ParseError::new(name, "no parameters")
+    } else {
+        ParseError::new(name, format!("a parameter, one of {}", P::NAMES.join(", ")))
+    }
+}
+
+/// Declares the parameters of a solver, as a struct with a public field for each and their
+/// defaults:
+///
+/// ```
+/// aoc2024::params! {
+///     pub struct MemoryParams {
+///         dims: aoc2024::Pos = aoc2024::Pos { x: 71, y: 71 },
+///         cutoff_time: usize = 1024,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $($(#[$field_meta:meta])* $field:ident: $ty:ty = $default:expr),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        $vis struct $name {
+            $($(#[$field_meta])* pub $field: $ty,)*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $($field: $default,)*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            const NAMES: &'static [&'static str] = &[$(stringify!($field)),*];
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), $crate::ParseError> {
+                match name {
+                    $(stringify!($field) => {
+                        self.$field = $crate::params::Param::parse_param(value)?;
+                    })*
+                    _ => return Err($crate::params::unknown::<Self>(name)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    params! {
+        struct TestParams {
+            dims: Pos = Pos { x: 71, y: 71 },
+            /// Documented
+            steps: usize = 1024,
+        }
+    }
+
+    fn overrides(s: &[(&str, &str)]) -> Vec<(String, String)> {
+        s.iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_params() {
+        assert_eq!(TestParams::NAMES, ["dims", "steps"]);
+        assert_eq!(TestParams::default().steps, 1024);
+        let params = TestParams::with(&overrides(&[("dims", "7x5"), ("steps", "12")])).unwrap();
+        assert_eq!(
+            params,
+            TestParams {
+                dims: Pos { x: 7, y: 5 },
+                steps: 12
+            }
+        );
+
+        let err = TestParams::with(&overrides(&[("dims", "7y5")])).unwrap_err();
+        assert_eq!(err.expected, "a size like 7x7 for dims");
+        let err = TestParams::with(&overrides(&[("dims", "0x5")])).unwrap_err();
+        assert_eq!(err.expected, "a size of at least 1x1 for dims");
+        let err = TestParams::with(&overrides(&[("steps", "-1")])).unwrap_err();
+        assert_eq!(err.expected, "a number for steps");
+        let err = TestParams::with(&overrides(&[("size", "7")])).unwrap_err();
+        assert_eq!(err.expected, "a parameter, one of dims, steps");
+        let err = <()>::with(&overrides(&[("size", "7")])).unwrap_err();
+        assert_eq!(
+            (err.expected.as_str(), err.found.as_str()),
+            ("no parameters", "size")
+        );
+    }
+}
@@ -0,0 +1,173 @@
+//! Helpers for fallible input parsing.
+//!
+//! Parsers usually work on slices of the input (sections, lines, tokens), so errors are
+//! created from the offending slice and only located, with `ParseError::locate`, against
+//! the whole input where it is available.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the offending text, 0 if not located yet
+    pub line: usize,
+    /// 1-based column, in chars, of the offending text, 0 if not located yet
+    pub column: usize,
+    /// What was expected
+    pub expected: String,
+    /// The offending text
+    pub found: String,
+    // Address of the offending text, used to locate it on the input
+    addr: usize,
+}
+
+impl ParseError {
+    /// Error on `found`, which should be a slice of the input
+    pub fn new(found: &str, expected: impl Into<String>) -> ParseError {
+        ParseError {
+            line: 0,
+            column: 0,
+            expected: expected.into(),
+            found: found.to_string(),
+            addr: found.as_ptr() as usize,
+        }
+    }
+
+    /// Sets the line and column of the error, if the offending text is a slice of `input`
+    pub fn locate(mut self, input: &str) -> ParseError {
+        let start = input.as_ptr() as usize;
+        if self.line == 0 && self.addr >= start && self.addr <= start + input.len() {
+            let before = &input[..self.addr - start];
+            self.line = before.matches('\n').count() + 1;
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            self.column = before[line_start..].chars().count() + 1;
+        }
+        self
+    }
+
+    /// Multi-line diagnostic pointing at the offending text on `input`
+    pub fn report(&self, input: &str) -> String {
+        let err = self.clone().locate(input);
+        let mut res = format!("error: {}", err.describe());
+        if let Some(text) = input.lines().nth(err.line.wrapping_sub(1)) {
+            let width = err.line.to_string().len();
+            let marks = err.found.lines().next().unwrap_or("").chars().count();
+            res += &format!(
+                "\n{:width$}--> line {}, column {}\n{:width$} |\n{} | {}\n{:width$} | {}{}",
+                "",
+                err.line,
+                err.column,
+                "",
+                err.line,
+                text,
+                "",
+                " ".repeat(err.column - 1),
+                "^".repeat(marks.max(1)),
+            );
+        }
+        res
+    }
+
+    fn describe(&self) -> String {
+        let found = self.found.lines().next().unwrap_or("");
+        if found.is_empty() {
+            format!("expected {}, found nothing", self.expected)
+        } else if found.chars().count() > 30 {
+            let found = found.chars().take(30).collect::<String>();
+            format!("expected {}, found \"{found}...\"", self.expected)
+        } else {
+            format!("expected {}, found \"{found}\"", self.expected)
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "line {}, column {}: ", self.line, self.column)?;
+        }
+        write!(f, "{}", self.describe())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a number
+pub fn num<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse().map_err(|_| ParseError::new(s, "a number"))
+}
+
+/// Splits on the first occurrence of `delim`
+pub fn split_once<'a>(s: &'a str, delim: &str) -> Result<(&'a str, &'a str), ParseError> {
+    s.split_once(delim)
+        .ok_or_else(|| ParseError::new(s, format!("{delim:?}")))
+}
+
+/// Removes `prefix` from the start
+pub fn strip_prefix<'a>(s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    s.strip_prefix(prefix)
+        .ok_or_else(|| ParseError::new(s, format!("{prefix:?}")))
+}
+
+/// Parses a single digit, at `idx` on `s`
+pub fn digit(s: &str, idx: usize) -> Result<u32, ParseError> {
+    let c = s[idx..].chars().next();
+    c.and_then(|c| c.to_digit(10))
+        .ok_or_else(|| ParseError::new(&s[idx..idx + c.map_or(0, char::len_utf8)], "a digit"))
+}
+
+/// Next element of `it`, which is described by `expected`
+pub fn next<'a>(
+    it: &mut impl Iterator<Item = &'a str>,
+    line: &'a str,
+    expected: &str,
+) -> Result<&'a str, ParseError> {
+    it.next()
+        .ok_or_else(|| ParseError::new(&line[line.len()..], expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "1 2\n3 x4\n";
+
+    #[test]
+    fn test_locate() {
+        let token = INPUT.lines().nth(1).unwrap().split(' ').nth(1).unwrap();
+        let err = num::<u32>(token).unwrap_err();
+        assert_eq!(err.line, 0);
+        let err = err.locate(INPUT);
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.found, "x4");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: expected a number, found \"x4\""
+        );
+        assert_eq!(
+            err.report(INPUT),
+            "error: expected a number, found \"x4\"\n --> line 2, column 3\n  |\n2 | 3 x4\n  |   ^^"
+        );
+
+        // Not a slice of the input, can't be located
+        let err = ParseError::new(&String::from("x4"), "a number").locate(INPUT);
+        assert_eq!((err.line, err.column), (0, 0));
+        assert_eq!(err.report(INPUT), "error: expected a number, found \"x4\"");
+    }
+
+    #[test]
+    fn test_helpers() {
+        assert_eq!(split_once("a: b", ": "), Ok(("a", "b")));
+        assert_eq!(split_once("a b", ": ").unwrap_err().expected, "\": \"");
+        assert_eq!(strip_prefix("p=1", "p="), Ok("1"));
+        assert_eq!(digit("12", 1), Ok(2));
+        assert_eq!(digit("1é", 1).unwrap_err().found, "é");
+
+        let line = "a b";
+        let mut it = line.split(' ');
+        assert_eq!(next(&mut it, line, "a"), Ok("a"));
+        assert_eq!(next(&mut it, line, "b"), Ok("b"));
+        let err = next(&mut it, line, "c").unwrap_err().locate(line);
+        assert_eq!((err.column, err.found.as_str()), (4, ""));
+    }
+}
@@ -0,0 +1,272 @@
+//! Summary of the runtimes of several days, in the same layout as the README's table, and
+//! JSON and CSV records of the results.
+
+use std::time::Duration;
+
+use aoc2024::{PartRun, Run};
+
+fn ms(time: Duration) -> String {
+    format!("{:.2}", time.as_secs_f64() * 1000.0)
+}
+
+/// Time of each part of a run. The input is parsed once for both parts, so its time is
+/// counted on the first part that was run
+pub fn part_times(run: &Run) -> (Duration, Duration) {
+    let time = |part: &Option<PartRun>| part.as_ref().map_or(Duration::ZERO, |p| p.time);
+    match run.part_one {
+        Some(_) => (run.parse_time + time(&run.part_one), time(&run.part_two)),
+        None => (Duration::ZERO, run.parse_time + time(&run.part_two)),
+    }
+}
+
+/// FNV-1a hash, to identify the inputs
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Quoted JSON string
+pub fn json_string(s: &str) -> String {
+    let mut res = String::with_capacity(s.len() + 2);
+    res.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => res += "\\\"",
+            '\\' => res += "\\\\",
+            '\n' => res += "\\n",
+            '\r' => res += "\\r",
+            '\t' => res += "\\t",
+            c if c.is_control() => res += &format!("\\u{:04x}", c as u32),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+/// CSV field, quoted if needed
+pub fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+enum Value {
+    Null,
+    Bool(bool),
+    Num(u128),
+    Str(String),
+}
+
+/// Result of a part of a day, or of a day that couldn't be run, for the machine readable
+/// formats
+pub struct Record<'a> {
+    pub day: u16,
+    /// Part and its result, or the error of a day that couldn't be run
+    pub part: Result<(u8, &'a PartRun), String>,
+    pub parse_time: Duration,
+    /// Whether the part was solved together with part one, which has its time
+    pub shared: bool,
+    pub input_path: &'a str,
+    /// Hash of the input, if it could be read
+    pub input_hash: Option<u64>,
+}
+
+impl Record<'_> {
+    pub const CSV_HEADER: &'static str =
+        "day,part,type,answer,error,parse_ns,time_ns,shared,input,input_hash";
+
+    fn fields(&self) -> [(&'static str, Value); 10] {
+        let (part, answer, error, time) = match &self.part {
+            Ok((n, run)) => {
+                let (answer, error) = match &run.answer {
+                    Ok(answer) => (Some(answer), None),
+                    Err(err) => (None, Some(err.to_string())),
+                };
+                (Value::Num(*n as u128), answer, error, Some(run.time))
+            }
+            Err(err) => (Value::Null, None, Some(err.clone()), None),
+        };
+        let parse_time = time.map(|_| self.parse_time);
+        let str_or_null = |s: Option<String>| s.map_or(Value::Null, Value::Str);
+        let ns_or_null = |t: Option<Duration>| t.map_or(Value::Null, |t| Value::Num(t.as_nanos()));
+        [
+            ("day", Value::Num(self.day as u128)),
+            ("part", part),
+            (
+                "type",
+                str_or_null(answer.map(|a| a.type_name().to_string())),
+            ),
+            ("answer", str_or_null(answer.map(|a| a.to_string()))),
+            ("error", str_or_null(error)),
+            ("parse_ns", ns_or_null(parse_time)),
+            ("time_ns", ns_or_null(time)),
+            ("shared", Value::Bool(self.shared)),
+            ("input", Value::Str(self.input_path.to_string())),
+            (
+                "input_hash",
+                str_or_null(self.input_hash.map(|h| format!("{h:016x}"))),
+            ),
+        ]
+    }
+
+    /// JSON object, on a single line
+    pub fn to_json(&self) -> String {
+        let fields = self.fields().map(|(name, value)| {
+            let value = match value {
+                Value::Null => "null".to_string(),
+                Value::Bool(v) => v.to_string(),
+                Value::Num(v) => v.to_string(),
+                Value::Str(v) => json_string(&v),
+            };
+            format!("\"{name}\":{value}")
+        });
+        format!("{{{}}}", fields.join(","))
+    }
+
+    /// CSV row, with the columns of `CSV_HEADER`
+    pub fn to_csv(&self) -> String {
+        let fields = self.fields().map(|(_, value)| match value {
+            Value::Null => String::new(),
+            Value::Bool(v) => v.to_string(),
+            Value::Num(v) => v.to_string(),
+            Value::Str(v) => csv_field(&v),
+        });
+        fields.join(",")
+    }
+}
+
+/// Markdown table with the time of each part of each day, in ms, and their total.
+/// Days that couldn't be run have no times, and don't count for the total
+pub fn runtime_table(times: &[(u16, Option<(Duration, Duration)>)]) -> String {
+    let mut res = String::from(
+        "|    Day    |    Part 1    |    Part 2     |\n\
+         |:---------:|:------------:|:-------------:|\n",
+    );
+    let mut total = (Duration::ZERO, Duration::ZERO);
+    for &(day, part_times) in times {
+        let (one, two) = match part_times {
+            Some((one, two)) => {
+                total = (total.0 + one, total.1 + two);
+                (ms(one), ms(two))
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+        res += &format!("|{day:^11}|{one:^14}|{two:^15}|\n");
+    }
+    let (one, two) = (
+        format!("**{} ms**", ms(total.0)),
+        format!("**{} ms**", ms(total.1)),
+    );
+    res += &format!("|{:^11}|{one:^14}|{two:^15}|", "**Total**");
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2024::{Answer, AoCError};
+
+    #[test]
+    fn test_part_times() {
+        let part = |ms| {
+            Some(PartRun {
+                answer: Ok(Answer::None),
+                time: Duration::from_millis(ms),
+                stats: Default::default(),
+            })
+        };
+        let mut run = Run {
+            parse_time: Duration::from_millis(1),
+            part_one: part(2),
+            part_two: part(3),
+            shared: false,
+        };
+        let ms = Duration::from_millis;
+        assert_eq!(part_times(&run), (ms(3), ms(3)));
+        run.part_one = None;
+        assert_eq!(part_times(&run), (ms(0), ms(4)));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(fnv1a(b""), 0xcbf29ce484222325);
+        assert_eq!(fnv1a(b"a"), 0xaf63dc4c8601ec8c);
+        assert_eq!(json_string("a\"b\\\n\u{1}"), "\"a\\\"b\\\\\\n\\u0001\"");
+        assert_eq!(csv_field("ab"), "ab");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+
+    #[test]
+    fn test_record() {
+        let run = PartRun {
+            answer: Ok(Answer::Int(-3)),
+            time: Duration::from_nanos(1200),
+            stats: Default::default(),
+        };
+        let mut record = Record {
+            day: 4,
+            part: Ok((1, &run)),
+            parse_time: Duration::from_nanos(300),
+            shared: false,
+            input_path: "data/input4",
+            input_hash: Some(0xaf63dc4c8601ec8c),
+        };
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":4,\"part\":1,\"type\":\"int\",\"answer\":\"-3\",\"error\":null,\
+             \"parse_ns\":300,\"time_ns\":1200,\"shared\":false,\"input\":\"data/input4\",\
+             \"input_hash\":\"af63dc4c8601ec8c\"}"
+        );
+        assert_eq!(
+            record.to_csv(),
+            "4,1,int,-3,,300,1200,false,data/input4,af63dc4c8601ec8c"
+        );
+        assert_eq!(Record::CSV_HEADER.split(',').count(), 10);
+
+        let run = PartRun {
+            answer: Err(AoCError::NoSolution("no path, at all".to_string())),
+            time: Duration::ZERO,
+            stats: Default::default(),
+        };
+        record.part = Ok((2, &run));
+        record.shared = true;
+        assert_eq!(
+            record.to_csv(),
+            "4,2,,,\"No solution: no path, at all\",300,0,true,data/input4,af63dc4c8601ec8c"
+        );
+
+        record.part = Err("Couldn't read file: x".to_string());
+        record.input_hash = None;
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":4,\"part\":null,\"type\":null,\"answer\":null,\
+             \"error\":\"Couldn't read file: x\",\"parse_ns\":null,\"time_ns\":null,\
+             \"shared\":true,\"input\":\"data/input4\",\"input_hash\":null}"
+        );
+    }
+
+    #[test]
+    fn test_runtime_table() {
+        let times = [
+            (
+                1,
+                Some((Duration::from_micros(110), Duration::from_micros(120))),
+            ),
+            (2, None),
+            (16, Some((Duration::from_micros(16110), Duration::ZERO))),
+        ];
+        assert_eq!(
+            runtime_table(&times),
+            "|    Day    |    Part 1    |    Part 2     |\n\
+             |:---------:|:------------:|:-------------:|\n\
+             |     1     |     0.11     |     0.12      |\n\
+             |     2     |      -       |       -       |\n\
+             |    16     |    16.11     |     0.00      |\n\
+             | **Total** | **16.22 ms** |  **0.12 ms**  |"
+        );
+    }
+}
@@ -0,0 +1,300 @@
+//! Scaffolding of a new day: its module from the template, registered on the library, and
+//! stubs for its input files and expected answers. Days of 2024 are on `src/days/`, and those
+//! of other years on a module of their own, like `src/year2023/`.
+
+use std::fs;
+use std::path::Path;
+
+use crate::cli;
+
+const TEMPLATE: &str = "src/day_template.rs.in";
+const LIB: &str = "src/lib.rs";
+const DAYS: &str = "src/days/mod.rs";
+
+/// Source of the module of `day`, from the template
+pub fn day_module(template: &str, day: u16) -> String {
+    template.replace("DayN", &format!("Day{day}"))
+}
+
+/// Source of the module of the days of `year`, with only `day`
+pub fn year_module(year: u16, day: u16) -> String {
+    format!(
+        "//! Days of {year}, registered on the library's `YEARS`.\n\
+         \n\
+         use crate::Entry;\n\
+         \n\
+         pub mod day{day};\n\
+         \n\
+         pub const DAYS: &[Entry] = &[\n\
+         \x20   // In order of the days\n\
+         \x20   Entry::new::<day{day}::Day{day}>({day}),\n\
+         ];\n"
+    )
+}
+
+// Inserts `new_line` among the lines for which `key_of` is some, in order of `key`. Without
+// any such lines it goes after the last one matching `fallback`. Returns whether it was
+// inserted
+fn insert_sorted<K: Ord>(
+    lines: &mut Vec<String>,
+    new_line: String,
+    key: K,
+    key_of: impl Fn(&str) -> Option<K>,
+    fallback: impl Fn(&str) -> bool,
+) -> bool {
+    let positions = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, key_of(line)?)))
+        .collect::<Vec<_>>();
+    let idx = match positions.iter().find(|(_, k)| *k > key) {
+        Some(&(i, _)) => i,
+        None => match positions.last() {
+            Some(&(i, _)) => i + 1,
+            None => match lines.iter().rposition(|line| fallback(line)) {
+                Some(i) => i + 1,
+                None => return false,
+            },
+        },
+    };
+    lines.insert(idx, new_line);
+    true
+}
+
+// Number of a module declaration, like `pub mod day{number};`
+fn module_number(line: &str, prefix: &str) -> Option<u16> {
+    line.strip_prefix(prefix)?.strip_suffix(';')?.parse().ok()
+}
+
+// Name of the module of a day, like `day17`. They are sorted by name, as rustfmt does
+fn module_day(line: &str) -> Option<String> {
+    module_number(line, "pub mod day").map(|day| format!("day{day}"))
+}
+
+fn module_year(line: &str) -> Option<u16> {
+    module_number(line, "pub mod year")
+}
+
+// Whether the line declares a module from a file
+fn is_module(line: &str) -> bool {
+    let line = line.strip_prefix("pub ").unwrap_or(line);
+    line.starts_with("mod ") && line.ends_with(';')
+}
+
+// Year of an entry of `YEARS`
+fn entry_year(line: &str) -> Option<u16> {
+    line.strip_prefix("    (")?.split_once(',')?.0.parse().ok()
+}
+
+/// Source of a registry of days, `days` or the module of a year at `path`, with the module of
+/// `day` declared, in the order of rustfmt, and registered on its `DAYS`, in order of the days
+pub fn register(src: &str, path: &str, day: u16) -> Result<String, String> {
+    let module = format!("pub mod day{day};");
+    let entry = format!("    Entry::new::<day{day}::Day{day}>({day}),");
+    if src.lines().any(|line| line == module || line == entry) {
+        return Err(format!("Day {day} is already registered in {path}"));
+    }
+
+    // Entries have the day first on the module path
+    let entry_day = |line: &str| {
+        let rest = line.strip_prefix("    Entry::new::<day")?;
+        rest[..rest.find("::")?].parse().ok()
+    };
+    let mut lines = src.lines().map(String::from).collect::<Vec<_>>();
+    let name = format!("day{day}");
+    if !insert_sorted(&mut lines, module, name, module_day, |_| false)
+        || !insert_sorted(&mut lines, entry, day, entry_day, |_| false)
+    {
+        return Err(format!(
+            "Couldn't find where to register day {day} in {path}"
+        ));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Source of the library with the module of `year` declared and registered on `YEARS`, both
+/// in order of the years
+pub fn register_year(lib: &str, year: u16) -> Result<String, String> {
+    let module = format!("pub mod year{year};");
+    let entry = format!("    ({year}, year{year}::DAYS),");
+    if lib
+        .lines()
+        .any(|line| line == module || entry_year(line) == Some(year))
+    {
+        return Err(format!("Year {year} is already registered in {LIB}"));
+    }
+
+    // The first year goes after the other modules, which come before it alphabetically
+    let mut lines = lib.lines().map(String::from).collect::<Vec<_>>();
+    if !insert_sorted(&mut lines, module, year, module_year, is_module)
+        || !insert_sorted(&mut lines, entry, year, entry_year, |_| false)
+    {
+        return Err(format!(
+            "Couldn't find where to register year {year} in {LIB}"
+        ));
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Commented out expected answers of `day`, to fill in once known
+pub fn answer_stubs(day: u16) -> String {
+    [format!("input{day}Test"), format!("input{day}")]
+        .iter()
+        .flat_map(|file| (1..=2).map(move |part| format!("# {day} {file} {part} ?\n")))
+        .collect()
+}
+
+/// Creates the module of `day` of `year`, registers it, along with the year if it is new, and
+/// creates its input files and expected answer stubs. Must be run from the root of the repo.
+/// Nothing is overwritten, and nothing is changed if the day already exists. Returns the
+/// files that were created or changed
+pub fn new_day(year: u16, day: u16) -> Result<Vec<String>, String> {
+    let read = |path: &str| {
+        fs::read_to_string(path)
+            .map_err(|_| format!("Couldn't read {path}, new must be run from the root of the repo"))
+    };
+    let write = |path: &str, contents: &str| {
+        fs::write(path, contents).map_err(|err| format!("Couldn't write {path}: {err}"))
+    };
+    let create_dir = |path: &str| {
+        fs::create_dir_all(path).map_err(|err| format!("Couldn't create {path}: {err}"))
+    };
+
+    let (dir, registry) = if year == cli::DEFAULT_YEAR {
+        ("src/days".to_string(), DAYS.to_string())
+    } else {
+        (format!("src/year{year}"), format!("src/year{year}/mod.rs"))
+    };
+    let module_path = format!("{dir}/day{day}.rs");
+    if Path::new(&module_path).exists() {
+        return Err(format!("Day {day} already exists in {module_path}"));
+    }
+    let module = day_module(&read(TEMPLATE)?, day);
+    // A new year has its module created, and is registered on the library
+    let (registry_src, lib) = if Path::new(&registry).exists() {
+        (register(&read(&registry)?, &registry, day)?, None)
+    } else {
+        (
+            year_module(year, day),
+            Some(register_year(&read(LIB)?, year)?),
+        )
+    };
+    let data = cli::data_dir(year);
+    let answers_path = format!("{data}/answers");
+    let answers = fs::read_to_string(&answers_path).unwrap_or_default();
+
+    create_dir(&dir)?;
+    write(&module_path, &module)?;
+    write(&registry, &registry_src)?;
+    let mut changed = vec![module_path, registry];
+    if let Some(lib) = lib {
+        write(LIB, &lib)?;
+        changed.push(LIB.to_string());
+    }
+    create_dir(&data)?;
+    for file in [
+        format!("{data}/input{day}"),
+        format!("{data}/input{day}Test"),
+    ] {
+        if !Path::new(&file).exists() {
+            write(&file, "")?;
+            changed.push(file);
+        }
+    }
+    let day_field = day.to_string();
+    if !answers
+        .lines()
+        .any(|line| line.split(' ').next() == Some(&day_field))
+    {
+        write(&answers_path, &(answers + &answer_stubs(day)))?;
+        changed.push(answers_path);
+    }
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS_SRC: &str = "use crate::Entry;\n\
+        \n\
+        pub mod day1;\n\
+        pub mod day3;\n\
+        \n\
+        pub const DAYS: &[Entry] = &[\n\
+        \x20   Entry::new::<day1::Day1>(1),\n\
+        \x20   Entry::new::<day3::Day3>(3),\n\
+        ];\n";
+
+    const LIB_SRC: &str = "mod answer;\n\
+        pub mod days;\n\
+        pub mod visual;\n\
+        \n\
+        pub const YEARS: &[(u16, &[Entry])] = &[\n\
+        \x20   (2024, days::DAYS), // Comment\n\
+        ];\n\
+        \n\
+        #[cfg(test)]\n\
+        mod tests {}\n";
+
+    #[test]
+    fn test_register() {
+        let days = register(DAYS_SRC, DAYS, 2).unwrap();
+        assert_eq!(
+            days,
+            "use crate::Entry;\n\
+             \n\
+             pub mod day1;\n\
+             pub mod day2;\n\
+             pub mod day3;\n\
+             \n\
+             pub const DAYS: &[Entry] = &[\n\
+             \x20   Entry::new::<day1::Day1>(1),\n\
+             \x20   Entry::new::<day2::Day2>(2),\n\
+             \x20   Entry::new::<day3::Day3>(3),\n\
+             ];\n"
+        );
+        let days = register(DAYS_SRC, DAYS, 4).unwrap();
+        assert!(days.contains("pub mod day3;\npub mod day4;\n\npub const"));
+        assert!(days.contains("(3),\n    Entry::new::<day4::Day4>(4),\n];"));
+        // Modules are sorted by name, days on DAYS by number
+        let days = register(&days, DAYS, 10).unwrap();
+        assert!(days.contains("pub mod day1;\npub mod day10;\npub mod day3;\n"));
+        assert!(days.contains("(4),\n    Entry::new::<day10::Day10>(10),\n];"));
+        assert!(register(DAYS_SRC, DAYS, 3).is_err());
+        assert!(register("fn main() {}\n", DAYS, 3).is_err());
+
+        // The days of 2024 are registered in the same way
+        assert!(register(include_str!("days/mod.rs"), DAYS, 26).is_ok());
+    }
+
+    #[test]
+    fn test_register_year() {
+        let lib = register_year(LIB_SRC, 2023).unwrap();
+        assert!(lib.contains("pub mod visual;\npub mod year2023;\n\npub const"));
+        assert!(
+            lib.contains("&[\n    (2023, year2023::DAYS),\n    (2024, days::DAYS), // Comment\n];")
+        );
+        let lib = register_year(&lib, 2025).unwrap();
+        assert!(lib.contains("pub mod year2023;\npub mod year2025;\n"));
+        assert!(lib.contains("(2024, days::DAYS), // Comment\n    (2025, year2025::DAYS),\n];"));
+        assert!(register_year(&lib, 2025).is_err());
+        assert!(register_year(LIB_SRC, 2024).is_err());
+        assert!(register_year(include_str!("lib.rs"), 2023).is_ok());
+
+        // The modules of the years register further days
+        let module = register(&year_module(2023, 5), "mod.rs", 2).unwrap();
+        assert!(module.contains("pub mod day2;\npub mod day5;\n"));
+        assert!(module.contains("days\n    Entry::new::<day2::Day2>(2),\n    Entry::new::<day5"));
+    }
+
+    #[test]
+    fn test_stubs() {
+        assert_eq!(
+            day_module("struct DayN;\nimpl Solver for DayN", 7),
+            "struct Day7;\nimpl Solver for Day7"
+        );
+        assert_eq!(answer_stubs(7).lines().next(), Some("# 7 input7Test 1 ?"));
+        assert_eq!(answer_stubs(7).lines().count(), 4);
+    }
+}
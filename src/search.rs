@@ -0,0 +1,325 @@
+//! Generic graph searches, parameterised by a successors function.
+//!
+//! All searches return a `SearchResult` with the minimum cost to each explored node and its
+//! predecessors, from which the paths can be reconstructed. The `_all` variants keep every
+//! optimal predecessor of each node, and keep searching until all goals with the minimum
+//! cost are found. Each search is recorded on the `searches` span, and each node it pops on
+//! the `search pops` counter.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+use crate::stats;
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C> {
+    /// Minimum cost to reach each explored node
+    pub cost: HashMap<N, C>,
+    /// Predecessors of each node on its optimal paths. The start node has none
+    pub prev: HashMap<N, Vec<N>>,
+    /// Goals found, all with the same minimum cost
+    pub goals: Vec<N>,
+}
+
+impl<N: Copy + Eq + Hash, C: Copy> SearchResult<N, C> {
+    /// First goal found
+    pub fn goal(&self) -> Option<N> {
+        self.goals.first().copied()
+    }
+
+    /// Cost of the first goal found
+    pub fn goal_cost(&self) -> Option<C> {
+        self.goal().map(|goal| self.cost[&goal])
+    }
+
+    /// Path from the start to `node`, following the first predecessor of each node
+    pub fn path(&self, node: N) -> Option<Vec<N>> {
+        if !self.cost.contains_key(&node) {
+            return None;
+        }
+        let mut path = vec![node];
+        while let Some(prev) = self.prev.get(path.last().unwrap()) {
+            path.push(prev[0]);
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All nodes that are on an optimal path from the start to any of `targets`
+    pub fn path_nodes(&self, targets: &[N]) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut frontier = targets.to_vec();
+        while let Some(node) = frontier.pop() {
+            if nodes.insert(node) {
+                frontier.extend(self.prev.get(&node).into_iter().flatten());
+            }
+        }
+        nodes
+    }
+
+    /// Number of different optimal paths from the start to `node`
+    pub fn count_paths(&self, node: N) -> u64 {
+        fn count<N: Copy + Eq + Hash>(
+            node: N,
+            prev: &HashMap<N, Vec<N>>,
+            memo: &mut HashMap<N, u64>,
+        ) -> u64 {
+            if let Some(&res) = memo.get(&node) {
+                return res;
+            }
+            let res = match prev.get(&node) {
+                None => 1,
+                Some(prevs) => prevs.iter().map(|&p| count(p, prev, memo)).sum(),
+            };
+            memo.insert(node, res);
+            res
+        }
+
+        if !self.cost.contains_key(&node) {
+            return 0;
+        }
+        count(node, &self.prev, &mut HashMap::new())
+    }
+}
+
+/// Breadth first search, where each step costs 1. Stops at the first goal found
+pub fn bfs<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_impl(start, successors, is_goal, false)
+}
+
+/// Breadth first search keeping all the optimal predecessors of each node
+pub fn bfs_all<N, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    bfs_impl(start, successors, is_goal, true)
+}
+
+fn bfs_impl<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+    all_paths: bool,
+) -> SearchResult<N, usize>
+where
+    N: Copy + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut res = SearchResult {
+        cost: HashMap::from([(start, 0)]),
+        prev: HashMap::new(),
+        goals: Vec::new(),
+    };
+    let mut frontier = VecDeque::from([start]);
+    let mut goal_cost = None;
+
+    let _span = stats::span("searches");
+    while let Some(node) = frontier.pop_front() {
+        stats::count("search pops");
+        let cost = res.cost[&node];
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            // Already found all goals with minimum cost
+            break;
+        }
+        if is_goal(&node) {
+            goal_cost = Some(cost);
+            res.goals.push(node);
+            if all_paths {
+                continue;
+            }
+            break;
+        }
+        for next in successors(&node) {
+            match res.cost.get(&next) {
+                None => {
+                    res.cost.insert(next, cost + 1);
+                    res.prev.insert(next, vec![node]);
+                    frontier.push_back(next);
+                }
+                Some(&next_cost) if all_paths && next_cost == cost + 1 => {
+                    res.prev.get_mut(&next).unwrap().push(node);
+                }
+                _ => {}
+            }
+        }
+    }
+    res
+}
+
+/// Dijkstra search, with successors returning the next nodes and the cost to reach them.
+/// Stops at the first goal found
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Copy + Eq + Hash + Ord,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, successors, |_| C::default(), is_goal, false)
+}
+
+/// Dijkstra search keeping all the optimal predecessors of each node
+pub fn dijkstra_all<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Copy + Eq + Hash + Ord,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, successors, |_| C::default(), is_goal, true)
+}
+
+/// A* search, `heuristic` must never overestimate the cost to reach a goal.
+/// Stops at the first goal found
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> SearchResult<N, C>
+where
+    N: Copy + Eq + Hash + Ord,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    search(start, successors, heuristic, is_goal, false)
+}
+
+// Uniform cost search, ordered by cost plus heuristic
+fn search<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+    all_paths: bool,
+) -> SearchResult<N, C>
+where
+    N: Copy + Eq + Hash + Ord,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let zero = C::default();
+    let mut res = SearchResult {
+        cost: HashMap::from([(start, zero)]),
+        prev: HashMap::new(),
+        goals: Vec::new(),
+    };
+    // Min-heap on the estimated total cost
+    let mut frontier = BinaryHeap::from([Reverse((heuristic(&start), zero, start))]);
+    let mut goal_cost = None;
+
+    let _span = stats::span("searches");
+    while let Some(Reverse((_, cost, node))) = frontier.pop() {
+        stats::count("search pops");
+        if cost > res.cost[&node] {
+            // Already visited this node with less cost
+            continue;
+        }
+        if goal_cost.is_some_and(|goal_cost| cost > goal_cost) {
+            // Already found all goals with minimum cost
+            break;
+        }
+        if is_goal(&node) {
+            goal_cost = Some(cost);
+            res.goals.push(node);
+            if all_paths {
+                continue;
+            }
+            break;
+        }
+        for (next, step_cost) in successors(&node) {
+            let next_cost = cost + step_cost;
+            match res.cost.get(&next) {
+                Some(&prev_cost) if next_cost > prev_cost => {}
+                Some(&prev_cost) if next_cost == prev_cost => {
+                    if all_paths {
+                        res.prev.get_mut(&next).unwrap().push(node);
+                    }
+                }
+                _ => {
+                    res.cost.insert(next, next_cost);
+                    res.prev.insert(next, vec![node]);
+                    frontier.push(Reverse((next_cost + heuristic(&next), next_cost, next)));
+                }
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Pos};
+
+    // Diamond shaped graph with two optimal paths from 0 to 3, and a more expensive one
+    fn weighted(node: &u8) -> Vec<(u8, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 1), (3, 5)],
+            1 | 2 => vec![(3, 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let res = dijkstra(0u8, weighted, |&n| n == 3);
+        assert_eq!(res.goal_cost(), Some(2));
+        assert_eq!(res.path(3), Some(vec![0, 1, 3]));
+        assert_eq!(res.count_paths(3), 1);
+
+        let res = dijkstra_all(0u8, weighted, |&n| n == 3);
+        assert_eq!(res.goal_cost(), Some(2));
+        assert_eq!(res.count_paths(3), 2);
+        assert_eq!(res.path_nodes(&[3]), HashSet::from([0, 1, 2, 3]));
+
+        let res = dijkstra(0u8, weighted, |&n| n == 4);
+        assert_eq!(res.goal(), None);
+        assert_eq!(res.path(4), None);
+    }
+
+    #[test]
+    fn test_grid_searches() {
+        let grid = Grid::parse("S..#\n.#..\n...E\n").unwrap();
+        let start = grid.find(&'S').unwrap();
+        let end = grid.find(&'E').unwrap();
+        let succ = |p: &Pos| {
+            let p = *p;
+            grid.neighbors(p).filter(|&n| grid[n] != '#')
+        };
+
+        let res = bfs(start, succ, |&p| p == end);
+        assert_eq!(res.goal_cost(), Some(5));
+        let path = res.path(end).unwrap();
+        assert_eq!((path[0], path[5]), (start, end));
+
+        let res = bfs_all(start, succ, |&p| p == end);
+        assert_eq!(res.count_paths(end), 3);
+        assert_eq!(res.path_nodes(&[end]).len(), 10);
+
+        let manhattan = |p: &Pos| p.x.abs_diff(end.x) + p.y.abs_diff(end.y);
+        let res = astar(start, |p| succ(p).map(|n| (n, 1)), manhattan, |&p| p == end);
+        assert_eq!(res.goal_cost(), Some(5));
+    }
+}
@@ -0,0 +1,354 @@
+//! Common interface of the daily solutions, and registry entries to list and run them.
+
+use std::time::{Duration, Instant};
+
+use crate::params::{Overrides, Params};
+use crate::stats::{self, Stats};
+use crate::{normalize_input, AoCResult, ParseError};
+
+/// Solution of a day's puzzle. The input is parsed once and shared by both parts
+pub trait Solver {
+    /// Parsed input, which may borrow from the input text
+    type Input<'a>;
+
+    /// Parameters of the puzzle, declared with `params!`, or `()` for puzzles without any
+    type Params: Params;
+
+    /// Whether `solve_both` shares work between the parts, instead of solving each in turn
+    const SHARED: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    /// Parses the input with the given parameters. Days with parameters override this, keeping
+    /// them on their input, and parse with the defaults on `parse`
+    fn parse_with<'a>(
+        input: &'a str,
+        params: &Self::Params,
+    ) -> Result<Self::Input<'a>, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> AoCResult;
+
+    fn part_two(input: &Self::Input<'_>) -> AoCResult;
+
+    /// Solves both parts. Days whose parts share work override this, and set `SHARED`, so
+    /// that the work is only done once
+    fn solve_both(input: &Self::Input<'_>) -> (AoCResult, AoCResult) {
+        (Self::part_one(input), Self::part_two(input))
+    }
+
+    /// Normalizes and parses the input, and solves part one
+    fn solve_part_one(input: &str) -> AoCResult {
+        Self::solve_part_one_with(input, &Self::Params::default())
+    }
+
+    /// Normalizes and parses the input, and solves part two
+    fn solve_part_two(input: &str) -> AoCResult {
+        Self::solve_part_two_with(input, &Self::Params::default())
+    }
+
+    /// Normalizes and parses the input with the given parameters, and solves part one
+    fn solve_part_one_with(input: &str, params: &Self::Params) -> AoCResult {
+        Self::part_one(&Self::parse_with(&normalize_input(input), params)?)
+    }
+
+    /// Normalizes and parses the input with the given parameters, and solves part two
+    fn solve_part_two_with(input: &str, params: &Self::Params) -> AoCResult {
+        Self::part_two(&Self::parse_with(&normalize_input(input), params)?)
+    }
+}
+
+/// Answer of a part, the time it took and the stats it recorded
+#[derive(Debug)]
+pub struct PartRun {
+    pub answer: AoCResult,
+    pub time: Duration,
+    pub stats: Stats,
+}
+
+/// Parts of a puzzle to run
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parts {
+    One,
+    Two,
+    Both,
+}
+
+impl Parts {
+    pub fn has_one(&self) -> bool {
+        *self != Parts::Two
+    }
+
+    pub fn has_two(&self) -> bool {
+        *self != Parts::One
+    }
+}
+
+/// Results of running the parts of a solver on an input. When the parts are solved
+/// together, the time and stats of both are on part one and part two has none
+#[derive(Debug)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub part_one: Option<PartRun>,
+    pub part_two: Option<PartRun>,
+    pub shared: bool,
+}
+
+/// Solver registered for a day. The solver's input type is erased, so that all days can be
+/// listed together
+#[derive(Debug, Clone, Copy)]
+pub struct Entry {
+    pub day: u16,
+    /// Names of the parameters of the puzzle
+    pub params: &'static [&'static str],
+    run: fn(&str, Parts, &Overrides) -> Result<Run, ParseError>,
+    check_params: fn(&Overrides) -> Result<(), ParseError>,
+}
+
+impl Entry {
+    pub const fn new<S: Solver>(day: u16) -> Entry {
+        Entry {
+            day,
+            params: S::Params::NAMES,
+            run: run::<S>,
+            check_params: |params| S::Params::with(params).map(drop),
+        }
+    }
+
+    // Parameters of the puzzle among `params`
+    fn own_params(&self, params: &Overrides) -> Vec<(String, String)> {
+        params
+            .iter()
+            .filter(|(name, _)| self.params.contains(&name.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// Normalizes and parses the input, and runs the given parts, timing each step. Parse
+    /// errors are located on the normalized input
+    pub fn run(&self, input: &str, parts: Parts) -> Result<Run, ParseError> {
+        (self.run)(input, parts, &[])
+    }
+
+    /// Runs the given parts like `run`, with the parameters of the puzzle overridden by name.
+    /// Those the puzzle doesn't have are ignored, so that the same can be given to several days
+    pub fn run_with(
+        &self,
+        input: &str,
+        parts: Parts,
+        params: &Overrides,
+    ) -> Result<Run, ParseError> {
+        (self.run)(input, parts, &self.own_params(params))
+    }
+
+    /// Checks the values of the parameters of the puzzle, ignoring the others like `run_with`
+    pub fn check_params(&self, params: &Overrides) -> Result<(), ParseError> {
+        (self.check_params)(&self.own_params(params))
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+// Times `f` and collects its stats
+fn measured<T>(f: impl FnOnce() -> T) -> (T, Duration, Stats) {
+    let ((res, time), stats) = stats::collect(|| timed(f));
+    (res, time, stats)
+}
+
+fn run<S: Solver>(input: &str, parts: Parts, params: &Overrides) -> Result<Run, ParseError> {
+    let params = S::Params::with(params)?;
+    let input = normalize_input(input);
+    let (parsed, parse_time) = timed(|| S::parse_with(&input, &params));
+    let parsed = parsed.map_err(|err| err.locate(&input))?;
+    let shared = S::SHARED && parts == Parts::Both;
+    let (part_one, part_two) = if shared {
+        let ((one, two), time, stats) = measured(|| S::solve_both(&parsed));
+        let part_one = PartRun {
+            answer: one,
+            time,
+            stats,
+        };
+        let part_two = PartRun {
+            answer: two,
+            time: Duration::ZERO,
+            stats: Stats::default(),
+        };
+        (Some(part_one), Some(part_two))
+    } else {
+        let part_one = parts.has_one().then(|| {
+            let (answer, time, stats) = measured(|| S::part_one(&parsed));
+            PartRun {
+                answer,
+                time,
+                stats,
+            }
+        });
+        let part_two = parts.has_two().then(|| {
+            let (answer, time, stats) = measured(|| S::part_two(&parsed));
+            PartRun {
+                answer,
+                time,
+                stats,
+            }
+        });
+        (part_one, part_two)
+    };
+    Ok(Run {
+        parse_time,
+        part_one,
+        part_two,
+        shared,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, Answer};
+
+    // Sums and multiplies a list of numbers
+    struct Numbers;
+
+    impl Solver for Numbers {
+        type Input<'a> = Vec<i64>;
+        type Params = ();
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            input.trim_end().split(',').map(parse::num).collect()
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> AoCResult {
+            Ok(Answer::from(input.iter().sum::<i64>()))
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> AoCResult {
+            Ok(Answer::from(input.iter().product::<i64>()))
+        }
+    }
+
+    // Min and max of a list of numbers, found in a single pass
+    struct Bounds;
+
+    impl Solver for Bounds {
+        type Input<'a> = Vec<i64>;
+        type Params = ();
+        const SHARED: bool = true;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Numbers::parse(input)
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> AoCResult {
+            Self::solve_both(input).0
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> AoCResult {
+            Self::solve_both(input).1
+        }
+
+        fn solve_both(input: &Self::Input<'_>) -> (AoCResult, AoCResult) {
+            let (min, max) = input.iter().fold((i64::MAX, i64::MIN), |(min, max), &v| {
+                (min.min(v), max.max(v))
+            });
+            (Ok(Answer::from(min)), Ok(Answer::from(max)))
+        }
+    }
+
+    crate::params! {
+        struct ShiftParams {
+            shift: i64 = 0,
+        }
+    }
+
+    // Sum and product of a list of numbers, shifted by a parameter
+    struct Shifted;
+
+    impl Solver for Shifted {
+        type Input<'a> = (Vec<i64>, ShiftParams);
+        type Params = ShiftParams;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Self::parse_with(input, &ShiftParams::default())
+        }
+
+        fn parse_with<'a>(
+            input: &'a str,
+            params: &ShiftParams,
+        ) -> Result<Self::Input<'a>, ParseError> {
+            Ok((Numbers::parse(input)?, *params))
+        }
+
+        fn part_one((input, params): &Self::Input<'_>) -> AoCResult {
+            Ok(Answer::from(input.iter().sum::<i64>() + params.shift))
+        }
+
+        fn part_two((input, params): &Self::Input<'_>) -> AoCResult {
+            Ok(Answer::from(input.iter().product::<i64>() + params.shift))
+        }
+    }
+
+    #[test]
+    fn test_entry() {
+        const ENTRY: Entry = Entry::new::<Numbers>(1);
+        assert_eq!(Numbers::solve_part_one("2,3,4").unwrap(), 9);
+
+        let run = ENTRY.run("2,3,4", Parts::Both).unwrap();
+        assert_eq!(run.part_one.unwrap().answer.unwrap(), 9);
+        assert_eq!(run.part_two.unwrap().answer.unwrap(), 24);
+        assert!(!run.shared);
+
+        let run = ENTRY.run("2,3,4", Parts::Two).unwrap();
+        assert!(run.part_one.is_none());
+        assert_eq!(run.part_two.unwrap().answer.unwrap(), 24);
+
+        let err = ENTRY.run("2,x,4", Parts::Both).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 3));
+    }
+
+    #[test]
+    fn test_shared() {
+        assert_eq!(Bounds::solve_part_two("2,7,4").unwrap(), 7);
+
+        let entry = Entry::new::<Bounds>(2);
+        let run = entry.run("2,7,4", Parts::Both).unwrap();
+        assert!(run.shared);
+        assert_eq!(run.part_one.unwrap().answer.unwrap(), 2);
+        let part_two = run.part_two.unwrap();
+        assert_eq!(part_two.answer.unwrap(), 7);
+        assert_eq!(part_two.time, Duration::ZERO);
+
+        // Not shared when solving a single part
+        let run = entry.run("2,7,4", Parts::One).unwrap();
+        assert!(!run.shared && run.part_two.is_none());
+    }
+
+    #[test]
+    fn test_params() {
+        let params = ShiftParams { shift: 10 };
+        assert_eq!(Shifted::solve_part_one_with("2,3,4", &params).unwrap(), 19);
+        assert_eq!(Shifted::solve_part_two("2,3,4").unwrap(), 24);
+
+        let entry = Entry::new::<Shifted>(3);
+        assert_eq!(entry.params, ["shift"]);
+        let params = [("shift", "-4"), ("size", "7")]
+            .map(|(name, value)| (name.to_string(), value.to_string()));
+        // Parameters of other days are ignored
+        let run = entry.run_with("2,3,4", Parts::Both, &params).unwrap();
+        assert_eq!(run.part_one.unwrap().answer.unwrap(), 5);
+        assert_eq!(run.part_two.unwrap().answer.unwrap(), 20);
+        let params = [("shift".to_string(), "x".to_string())];
+        let err = entry.run_with("2,3,4", Parts::Both, &params).unwrap_err();
+        assert_eq!(err.expected, "a number for shift");
+        assert_eq!(
+            entry.check_params(&params).unwrap_err().expected,
+            err.expected
+        );
+        assert!(Entry::new::<Numbers>(1).params.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+//! Counters and spans measuring the work done by the solutions, like the states popped by a
+//! search or the hits of a memo. They are compiled out unless the `stats` feature is on, and
+//! are only recorded while `collect` is running on the same thread, as it is for each part
+//! run by an `Entry`. The work of `par` is recorded on the threads that started it.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+#[cfg(feature = "stats")]
+use std::cell::RefCell;
+#[cfg(feature = "stats")]
+use std::time::Instant;
+
+/// Times a counter or span was hit, and the total time spent on a span
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stat {
+    pub count: u64,
+    pub time: Duration,
+}
+
+/// Stats recorded, by name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stats(BTreeMap<&'static str, Stat>);
+
+impl Stats {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn get(&self, name: &str) -> Option<Stat> {
+        self.0.get(name).copied()
+    }
+
+    /// Stats by name, in alphabetical order
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, Stat)> + '_ {
+        self.0.iter().map(|(&name, &stat)| (name, stat))
+    }
+
+    fn record(&mut self, name: &'static str, count: u64, time: Duration) {
+        let stat = self.0.entry(name).or_default();
+        stat.count += count;
+        stat.time += time;
+    }
+
+    /// Adds the stats of `other` to these
+    pub fn merge(&mut self, other: &Stats) {
+        for (name, stat) in other.iter() {
+            self.record(name, stat.count, stat.time);
+        }
+    }
+}
+
+// Counters are listed with their count, and spans with their total time too
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (name, stat)) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{name}: {}", stat.count)?;
+            if !stat.time.is_zero() {
+                write!(f, " in {:?}", stat.time)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "stats")]
+thread_local! {
+    static STATS: RefCell<Option<Stats>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "stats")]
+fn record(name: &'static str, count: u64, time: Duration) {
+    STATS.with(|stats| {
+        if let Some(stats) = stats.borrow_mut().as_mut() {
+            stats.record(name, count, time);
+        }
+    });
+}
+
+/// Adds `n` to the counter `name`
+#[inline]
+pub fn add(name: &'static str, n: u64) {
+    #[cfg(feature = "stats")]
+    record(name, n, Duration::ZERO);
+    #[cfg(not(feature = "stats"))]
+    let _ = (name, n);
+}
+
+/// Adds one to the counter `name`
+#[inline]
+pub fn count(name: &'static str) {
+    add(name, 1)
+}
+
+/// Span of code, counted and timed from its creation until it is dropped. The time of nested
+/// spans with the same name is counted on each of them
+#[must_use = "the span ends when dropped"]
+pub struct Span {
+    #[cfg(feature = "stats")]
+    name: &'static str,
+    #[cfg(feature = "stats")]
+    start: Instant,
+}
+
+/// Starts the span `name`, which ends when the returned guard is dropped
+#[inline]
+pub fn span(name: &'static str) -> Span {
+    #[cfg(not(feature = "stats"))]
+    let _ = name;
+    Span {
+        #[cfg(feature = "stats")]
+        name,
+        #[cfg(feature = "stats")]
+        start: Instant::now(),
+    }
+}
+
+#[cfg(feature = "stats")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        record(self.name, 1, self.start.elapsed());
+    }
+}
+
+/// Runs `f`, returning its result and the stats recorded meanwhile on this thread, which are
+/// empty without the `stats` feature
+pub fn collect<R>(f: impl FnOnce() -> R) -> (R, Stats) {
+    #[cfg(feature = "stats")]
+    let previous = STATS.with(|stats| stats.replace(Some(Stats::default())));
+    let res = f();
+    #[cfg(feature = "stats")]
+    let stats = STATS
+        .with(|stats| stats.replace(previous))
+        .unwrap_or_default();
+    #[cfg(not(feature = "stats"))]
+    let stats = Stats::default();
+    (res, stats)
+}
+
+/// Adds stats collected elsewhere, like on other threads, to those being collected on this one
+pub fn merge(stats: &Stats) {
+    #[cfg(feature = "stats")]
+    STATS.with(|current| {
+        if let Some(current) = current.borrow_mut().as_mut() {
+            current.merge(stats);
+        }
+    });
+    #[cfg(not(feature = "stats"))]
+    let _ = stats;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect() {
+        let (res, stats) = collect(|| {
+            count("pops");
+            add("pops", 2);
+            let _span = span("search");
+            let (_, inner) = collect(|| count("walks"));
+            merge(&inner);
+            4
+        });
+        assert_eq!(res, 4);
+        if cfg!(feature = "stats") {
+            assert_eq!(stats.get("pops").unwrap().count, 3);
+            assert_eq!(stats.get("walks").unwrap().count, 1);
+            // The span ends as the closure returns
+            assert_eq!(stats.get("search").unwrap().count, 1);
+        } else {
+            assert!(stats.is_empty());
+        }
+        // Nothing is recorded outside of collect
+        count("pops");
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = Stats::default();
+        stats.record("pops", 3, Duration::ZERO);
+        stats.record("expand", 2, Duration::from_millis(4));
+        let mut other = Stats::default();
+        other.record("pops", 1, Duration::ZERO);
+        stats.merge(&other);
+        assert_eq!(stats.get("pops").unwrap().count, 4);
+        assert_eq!(stats.to_string(), "expand: 2 in 4ms, pops: 4");
+    }
+}
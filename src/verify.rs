@@ -0,0 +1,225 @@
+//! Verification of the answers against the expected ones, recorded on an answers file with a
+//! line for each day, input file and part: `day file part answer [params]`. The parameters the
+//! part is solved with, if it needs other than the defaults, are a last field like
+//! `dims=7x7,cutoff_time=12`.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use aoc2024::{Answer, AoCResult};
+
+use crate::cli;
+
+/// Expected answer of a part, and the parameters it is solved with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    pub answer: String,
+    pub params: Vec<(String, String)>,
+}
+
+/// Expected answers of the parts of each day and input file
+pub type Answers = BTreeMap<(u16, String), [Option<Expected>; 2]>;
+
+// Parameters of an answers line, `name=value` pairs separated by commas, if `s` is one
+fn parse_params(s: &str) -> Option<Vec<(String, String)>> {
+    s.split(',')
+        .map(|param| match param.split_once('=')? {
+            (name, value) if !name.is_empty() => Some((name.to_string(), value.to_string())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Parses an answers file. Empty lines and lines starting with `#` are ignored. The answer is
+/// the rest of the line, but for a last field of parameters
+pub fn parse_answers(s: &str) -> Result<Answers, String> {
+    let mut res = Answers::new();
+    for (i, line) in s.lines().enumerate() {
+        if line.trim().is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let invalid = || format!("Invalid answers, line {}: '{line}'", i + 1);
+        let mut fields = line.splitn(4, ' ');
+        let (Some(day), Some(file), Some(part), Some(rest)) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(invalid());
+        };
+        let day = day.parse().map_err(|_| invalid())?;
+        let idx = match part {
+            "1" => 0,
+            "2" => 1,
+            _ => return Err(invalid()),
+        };
+        let (answer, params) = match rest.rsplit_once(' ') {
+            Some((answer, params)) => match parse_params(params) {
+                Some(params) => (answer, params),
+                None => (rest, Vec::new()),
+            },
+            None => (rest, Vec::new()),
+        };
+        let expected = Expected {
+            answer: answer.to_string(),
+            params,
+        };
+        let answers = res.entry((day, file.to_string())).or_default();
+        if answers[idx].replace(expected).is_some() {
+            return Err(format!("Duplicate answer, line {}: '{line}'", i + 1));
+        }
+    }
+    Ok(res)
+}
+
+/// Input files of `day`: those with answers, and the `files` named `input{day}`, possibly
+/// followed by a suffix like `Test`
+pub fn input_files(day: u16, files: &[String], answers: &Answers) -> Vec<String> {
+    let mut res = files
+        .iter()
+        .filter(|file| cli::is_day_input(file, day))
+        .cloned()
+        .collect::<Vec<_>>();
+    for (_, file) in answers.keys().filter(|(d, _)| *d == day) {
+        if !res.contains(file) {
+            res.push(file.clone());
+        }
+    }
+    res.sort();
+    res
+}
+
+/// Result of verifying a part
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    /// Wrong answer or error, with the expected answer
+    Fail {
+        expected: String,
+        found: String,
+    },
+    /// No expected answer recorded, with the answer found
+    Missing(String),
+}
+
+impl Outcome {
+    /// Compares `result` with `expected`. Parts without a puzzle, which answer `None`, have
+    /// nothing to verify
+    pub fn new(expected: Option<&str>, result: &AoCResult) -> Option<Outcome> {
+        let found = match result {
+            Ok(Answer::None) if expected.is_none() => return None,
+            Ok(answer) => answer.to_string(),
+            Err(err) => format!("error: {err}"),
+        };
+        Some(match expected {
+            Some(expected) if expected == found => Outcome::Pass,
+            Some(expected) => Outcome::Fail {
+                expected: expected.to_string(),
+                found,
+            },
+            None => Outcome::Missing(found),
+        })
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Fail { expected, found } => {
+                write!(f, "FAIL, expected {expected}, found {found}")
+            }
+            Outcome::Missing(found) => write!(f, "missing, found {found}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc2024::AoCError;
+
+    const ANSWERS: &str = "# comment\n\
+        1 input1Test 1 11\n\
+        1 input1Test 2 a, b\n\
+        1 other 2 4\n\
+        2 input2 1 7\n\
+        2 input2Test 1 22 dims=7x7,cutoff_time=12\n\
+        2 input2Test 2 x=1 y\n";
+
+    fn expected(answer: &str, params: &[(&str, &str)]) -> Option<Expected> {
+        Some(Expected {
+            answer: answer.to_string(),
+            params: params
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = parse_answers(ANSWERS).unwrap();
+        assert_eq!(answers.len(), 4);
+        assert_eq!(
+            answers[&(1, "input1Test".to_string())],
+            [expected("11", &[]), expected("a, b", &[])]
+        );
+        assert_eq!(
+            answers[&(1, "other".to_string())],
+            [None, expected("4", &[])]
+        );
+        // Only a last field of name=value pairs is taken as parameters
+        assert_eq!(
+            answers[&(2, "input2Test".to_string())],
+            [
+                expected("22", &[("dims", "7x7"), ("cutoff_time", "12")]),
+                expected("x=1 y", &[])
+            ]
+        );
+
+        assert!(parse_answers("1 input1 3 11").is_err());
+        assert!(parse_answers("1 input1 1").is_err());
+        assert!(parse_answers("1 input1 1 11\n1 input1 1 12").is_err());
+
+        // The answers of the repo's inputs, the example and the real one of each day
+        let answers = parse_answers(include_str!("../data/answers")).unwrap();
+        assert!(answers
+            .keys()
+            .map(|(day, _)| *day)
+            .eq((1..=25).flat_map(|day| [day, day])));
+    }
+
+    #[test]
+    fn test_input_files() {
+        let answers = parse_answers(ANSWERS).unwrap();
+        let files = ["input1", "input10", "input1Test", "input2"].map(String::from);
+        assert_eq!(
+            input_files(1, &files, &answers),
+            ["input1", "input1Test", "other"]
+        );
+        assert_eq!(input_files(10, &files, &answers), ["input10"]);
+    }
+
+    #[test]
+    fn test_outcome() {
+        assert_eq!(
+            Outcome::new(Some("3"), &Ok(Answer::Int(3))),
+            Some(Outcome::Pass)
+        );
+        assert_eq!(
+            Outcome::new(Some("3"), &Ok(Answer::Int(4)))
+                .unwrap()
+                .to_string(),
+            "FAIL, expected 3, found 4"
+        );
+        let err = Err(AoCError::NoSolution("none".to_string()));
+        assert_eq!(
+            Outcome::new(Some("3"), &err).unwrap().to_string(),
+            "FAIL, expected 3, found error: No solution: none"
+        );
+        assert_eq!(
+            Outcome::new(None, &Ok(Answer::Int(4))),
+            Some(Outcome::Missing("4".to_string()))
+        );
+        assert_eq!(Outcome::new(None, &Ok(Answer::None)), None);
+    }
+}
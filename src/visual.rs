@@ -0,0 +1,327 @@
+//! Recording of the frames of grid simulations, and their export as an ANSI terminal
+//! animation, a plain text dump or PPM and PNG images.
+//!
+//! Solvers emit frames with `step` and `keyframe`, which only build them while `recorded` is
+//! running on the same thread, so that solving isn't slowed down otherwise. Step frames are
+//! sampled once there are more than `MAX_STEPS` of them, while keyframes are always kept.
+
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use crate::Grid;
+
+/// Step frames kept, above which every other one is dropped
+pub const MAX_STEPS: usize = 1000;
+
+/// State of a simulation, with a label describing it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub label: String,
+    pub grid: Grid<char>,
+}
+
+impl Frame {
+    pub fn new(label: impl Into<String>, grid: Grid<char>) -> Frame {
+        Frame {
+            label: label.into(),
+            grid,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    /// Frames, and whether each is a keyframe
+    frames: Vec<(Frame, bool)>,
+    /// Only one of every `stride` steps is recorded
+    stride: usize,
+    steps: usize,
+}
+
+impl Recorder {
+    fn push(&mut self, f: impl FnOnce() -> Frame, key: bool) {
+        if !key {
+            self.steps += 1;
+            if !(self.steps - 1).is_multiple_of(self.stride) {
+                return;
+            }
+        }
+        self.frames.push((f(), key));
+        if !key && self.frames.iter().filter(|(_, key)| !key).count() > MAX_STEPS {
+            // Keep every other step, and record half as many from now on
+            let mut i = 0;
+            self.frames.retain(|&(_, key)| {
+                i += usize::from(!key);
+                key || i % 2 == 1
+            });
+            self.stride *= 2;
+        }
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Whether frames are being recorded on this thread
+pub fn is_recording() -> bool {
+    RECORDER.with(|recorder| recorder.borrow().is_some())
+}
+
+fn push(f: impl FnOnce() -> Frame, key: bool) {
+    RECORDER.with(|recorder| {
+        if let Some(recorder) = recorder.borrow_mut().as_mut() {
+            recorder.push(f, key);
+        }
+    });
+}
+
+/// Records an intermediate step of a simulation, built by `f` only if it is kept
+pub fn step(f: impl FnOnce() -> Frame) {
+    push(f, false)
+}
+
+/// Records a notable state of a simulation, like its final one, which is always kept
+pub fn keyframe(f: impl FnOnce() -> Frame) {
+    push(f, true)
+}
+
+/// Runs `f`, returning its result and the frames recorded meanwhile on this thread. Frames
+/// emitted from other threads, like those of `par`, aren't recorded
+pub fn recorded<R>(f: impl FnOnce() -> R) -> (R, Vec<Frame>) {
+    let previous = RECORDER.with(|recorder| {
+        recorder.replace(Some(Recorder {
+            stride: 1,
+            ..Default::default()
+        }))
+    });
+    let res = f();
+    let recorder = RECORDER.with(|recorder| recorder.replace(previous));
+    let frames = recorder.unwrap().frames;
+    (res, frames.into_iter().map(|(frame, _)| frame).collect())
+}
+
+/// Colour of a cell. Walls are grey, empty cells dark, boxes brown, the robots and guards red
+/// and paths green. Other chars get a colour of their own
+pub fn color(c: char) -> [u8; 3] {
+    match c {
+        '#' => [128, 128, 128],
+        '.' | ' ' => [24, 24, 24],
+        'O' | '[' | ']' => [181, 101, 29],
+        '@' | '^' | '>' | 'v' | '<' => [220, 40, 40],
+        'X' | 'o' | '*' => [60, 200, 80],
+        _ => {
+            let [r, g, b, _] = (c as u32).wrapping_mul(2654435761).to_be_bytes();
+            [r | 64, g | 64, b | 64]
+        }
+    }
+}
+
+/// Frames as plain text, each one after its number and label
+pub fn to_text(frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .enumerate()
+        .map(|(i, frame)| format!("Frame {}, {}:\n{}\n", i + 1, frame.label, frame.grid))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Plays the frames as an animation on an ANSI terminal, redrawing the screen on each one,
+/// `delay` apart
+pub fn play(frames: &[Frame], out: &mut impl Write, delay: Duration) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        let mut screen = format!(
+            "\x1b[H\x1b[2J{} ({}/{})\n",
+            frame.label,
+            i + 1,
+            frames.len()
+        );
+        for row in frame.grid.rows() {
+            for &c in row {
+                let [r, g, b] = color(c);
+                screen += &format!("\x1b[38;2;{r};{g};{b}m{c}");
+            }
+            screen += "\x1b[0m\n";
+        }
+        out.write_all(screen.as_bytes())?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    Ok(())
+}
+
+// Width, height and RGB pixels of a frame, with each cell a square of `scale` pixels
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.grid.width() * scale, frame.grid.height() * scale);
+    let mut rgb = Vec::with_capacity(width * height * 3);
+    for row in frame.grid.rows() {
+        let line = row
+            .iter()
+            .flat_map(|&c| color(c).repeat(scale))
+            .collect::<Vec<_>>();
+        for _ in 0..scale {
+            rgb.extend_from_slice(&line);
+        }
+    }
+    (width, height, rgb)
+}
+
+/// Frame as a binary PPM image, with each cell a square of `scale` pixels
+pub fn to_ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height, rgb) = pixels(frame, scale);
+    let mut res = format!("P6\n{width} {height}\n255\n").into_bytes();
+    res.extend(rgb);
+    res
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in bytes {
+        crc ^= b as u32;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xedb88320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &v in bytes {
+        a = (a + v as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// Frame as a PNG image, with each cell a square of `scale` pixels. The image data isn't
+/// compressed, only stored on zlib blocks. Empty grids, or a scale of 0, give an empty image
+pub fn to_png(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height, rgb) = pixels(frame, scale);
+    // Each row starts with its filter type, none
+    let raw = (0..height)
+        .flat_map(|y| {
+            let row = &rgb[y * width * 3..(y + 1) * width * 3];
+            std::iter::once(0).chain(row.iter().copied())
+        })
+        .collect::<Vec<_>>();
+    let mut zlib = vec![0x78, 0x01];
+    let mut blocks = raw.chunks(u16::MAX as usize).collect::<Vec<_>>();
+    if blocks.is_empty() {
+        // The stream still ends on a final block
+        blocks.push(&[]);
+    }
+    for (i, block) in blocks.iter().enumerate() {
+        let len = block.len() as u16;
+        zlib.push((i == blocks.len() - 1) as u8);
+        zlib.extend(len.to_le_bytes());
+        zlib.extend((!len).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend(adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bit RGB, without interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut res = b"\x89PNG\r\n\x1a\n".to_vec();
+    for (kind, data) in [(b"IHDR", header), (b"IDAT", zlib), (b"IEND", Vec::new())] {
+        res.extend((data.len() as u32).to_be_bytes());
+        let start = res.len();
+        res.extend(kind);
+        res.extend(data);
+        let crc = crc32(&res[start..]);
+        res.extend(crc.to_be_bytes());
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pos;
+
+    fn frame(label: &str, s: &str) -> Frame {
+        Frame::new(label, Grid::parse(s).unwrap())
+    }
+
+    #[test]
+    fn test_recorded() {
+        // Frames aren't built unless recording
+        step(|| unreachable!());
+        assert!(!is_recording());
+
+        let (res, frames) = recorded(|| {
+            assert!(is_recording());
+            step(|| frame("a", "#."));
+            keyframe(|| frame("b", ".#"));
+            3
+        });
+        assert_eq!(res, 3);
+        assert_eq!(frames, [frame("a", "#."), frame("b", ".#")]);
+        assert!(!is_recording());
+    }
+
+    #[test]
+    fn test_sampling() {
+        let (_, frames) = recorded(|| {
+            for i in 0..MAX_STEPS * 3 {
+                step(|| frame(&i.to_string(), "."));
+                if i == 10 {
+                    keyframe(|| frame("key", "#"));
+                }
+            }
+        });
+        let steps = frames
+            .iter()
+            .filter(|f| f.label != "key")
+            .collect::<Vec<_>>();
+        assert!(steps.len() <= MAX_STEPS && steps.len() > MAX_STEPS / 2);
+        assert_eq!(steps[0].label, "0");
+        assert_eq!(steps[1].label, "4");
+        assert_eq!(frames.iter().filter(|f| f.label == "key").count(), 1);
+    }
+
+    #[test]
+    fn test_export() {
+        let frames = [frame("start", "#.\n.@"), frame("end", "..\n@#")];
+        assert_eq!(
+            to_text(&frames),
+            "Frame 1, start:\n#.\n.@\n\nFrame 2, end:\n..\n@#\n"
+        );
+
+        let mut out = Vec::new();
+        play(&frames, &mut out, Duration::ZERO).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[2J").count(), 2);
+        assert!(out.contains("end (2/2)\n\x1b[38;2;24;24;24m."));
+
+        let ppm = to_ppm(&frames[0], 3);
+        assert!(ppm.starts_with(b"P6\n6 6\n255\n"));
+        assert_eq!(ppm.len(), 11 + 6 * 6 * 3);
+        assert_eq!(ppm[11..14], color('#'));
+
+        let png = to_png(&frames[0], 3);
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x06\0\0\0\x06"));
+        // The standard empty end chunk
+        assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn test_export_empty() {
+        let empty = Frame::new("empty", Grid::new(Pos { x: 0, y: 3 }, '.'));
+        for (frame, scale) in [(&empty, 3), (&frame("a", "#.\n.@"), 0)] {
+            assert!(to_ppm(frame, scale).starts_with(b"P6\n0 "));
+            let png = to_png(frame, scale);
+            assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\0"));
+            assert!(png.ends_with(b"\0\0\0\0IEND\xae\x42\x60\x82"));
+        }
+    }
+}